@@ -0,0 +1,78 @@
+//! Compares totalling a stream of Money with `fold_total`, which converts and adds each item as
+//! it is read, with collecting the stream into a Vec for `total` first. Allocations are counted
+//! by a global allocator which wraps the system one. Both paths allocate while looking up the
+//! rate of each item, so the difference between them is the Vec.
+//!
+//! Run with `cargo bench --bench fold_total`.
+
+use rust_decimal_macros::dec;
+use rusty_money::{Currency, Exchange, ExchangeRate, Iso, Money};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+const ITEMS: i64 = 1_000_000;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn items(currency: &'static Currency) -> impl Iterator<Item = Money> {
+    (0..ITEMS).map(move |minor| Money::from_minor(minor, currency))
+}
+
+/// Runs a total once, printing its allocations and time.
+fn measure<F: Fn() -> Money>(name: &str, f: F) -> Money {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let total = black_box(f());
+    let elapsed = start.elapsed();
+    println!(
+        "{:<10} {:>9} allocations {:>12} bytes {:>8.1} ms",
+        name,
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+        elapsed.as_secs_f64() * 1000.0
+    );
+    total
+}
+
+fn main() {
+    let usd = Currency::get(Iso::USD);
+    let eur = Currency::get(Iso::EUR);
+    let mut exchange = Exchange::new();
+    exchange.add_or_update_rate(&ExchangeRate::new(usd, eur, dec!(0.92)).unwrap());
+
+    let collected = measure("vec total", || {
+        let items: Vec<Money> = items(usd).collect();
+        exchange.total(&items, eur).unwrap()
+    });
+    let folded = measure("fold_total", || {
+        exchange.fold_total(items(usd), eur).unwrap()
+    });
+    assert_eq!(collected, folded);
+}
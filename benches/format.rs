@@ -0,0 +1,56 @@
+//! Compares formatting through `format_in`, which reuses cached params for each currency and
+//! locale, with building the params from the locale on every call.
+//!
+//! Run with `cargo bench --bench format`.
+
+use rusty_money::{money, Formatter, LocalFormat, Locale, Money, Params, Position};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 200_000;
+const ROUNDS: usize = 10;
+
+fn uncached(money: &Money, locale: Locale) -> String {
+    let currency = money.currency();
+    let format = LocalFormat::from_locale(locale);
+    let params = Params {
+        digit_separator: format.digit_separator,
+        exponent_separator: format.exponent_separator,
+        separator_pattern: format.digit_separator_pattern(),
+        positions: vec![Position::Sign, Position::Symbol, Position::Amount],
+        rounding: Some(currency.exponent),
+        display_exponent: Some(currency.exponent),
+        symbol: Some(currency.symbol),
+        code: Some(currency.iso_alpha_code),
+        locale: Some(locale),
+        force_sign: false,
+        zero_display: None,
+    };
+    Formatter::money(money, params)
+}
+
+/// Returns the fastest of several runs, in nanoseconds per call.
+fn bench<F: Fn(&Money) -> String>(money: &Money, f: F) -> f64 {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..ITERATIONS {
+                black_box(f(black_box(money)));
+            }
+            start.elapsed().as_nanos() as f64 / f64::from(ITERATIONS)
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn main() {
+    let money = money!("-1234567.891", "USD");
+    assert_eq!(
+        money.format_in(Locale::EnUs),
+        uncached(&money, Locale::EnUs)
+    );
+
+    let uncached = bench(&money, |money| uncached(money, Locale::EnUs));
+    let cached = bench(&money, |money| money.format_in(Locale::EnUs));
+    println!("uncached {:>8.1} ns/iter", uncached);
+    println!("cached   {:>8.1} ns/iter", cached);
+}
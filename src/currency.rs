@@ -1,7 +1,9 @@
 mod iso;
 pub use crate::locale::{LocalFormat, Locale};
+use crate::rounding::RoundingStrategy;
 use crate::MoneyError;
 pub use iso::Iso;
+use rust_decimal::Decimal;
 use std::collections::HashMap;
 use std::fmt;
 
@@ -18,13 +20,24 @@ lazy_static! {
 /// Operations on Currencies pass around references, since they are unchanging.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Currency {
+    pub cash_rounding: Option<Decimal>,
     pub default_locale: Locale,
     pub exponent: u32,
+    /// A symbol which tells the currency apart from others sharing its symbol (e.g. US$), shown
+    /// in locales where its own symbol would be taken for another currency.
+    pub international_symbol: Option<&'static str>,
     pub iso_alpha_code: &'static str,
     pub iso_numeric_code: &'static str,
+    pub major_unit_name: &'static str,
+    pub minor_unit_name: &'static str,
     pub name: &'static str,
+    /// The strategy `Money::round` uses for this currency, or None for RoundingStrategy::HalfUp.
+    pub rounding_strategy: Option<RoundingStrategy>,
     pub symbol: &'static str,
     pub symbol_first: bool,
+    /// Whether a space separates the symbol from the amount (e.g. R$ 1.234,56), unless the
+    /// locale overrides it.
+    pub symbol_spacing: bool,
 }
 
 impl fmt::Display for Currency {
@@ -44,6 +57,25 @@ impl Currency {
         Currency::from_string(code.to_string()).unwrap()
     }
 
+    /// Returns a Currency given an alphabetic ISO-4217 currency code, like `get` at runtime.
+    ///
+    /// Codes which are not three upper case letters raise a MalformedCurrencyCode error, and
+    /// well-formed codes which are not supported raise an InvalidCurrency error.
+    pub fn get_by_code(code: &str) -> Result<&'static Currency, MoneyError> {
+        if !Currency::is_valid_code_format(code) {
+            return Err(MoneyError::MalformedCurrencyCode);
+        }
+        Currency::find_by_alpha_iso(code.to_string()).ok_or(MoneyError::InvalidCurrency)
+    }
+
+    /// Returns whether a str is formatted like an alphabetic ISO-4217 code (e.g. "USD").
+    ///
+    /// This only checks for exactly three upper case ASCII letters, and not whether the
+    /// currency is supported.
+    pub fn is_valid_code_format(code: &str) -> bool {
+        code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase())
+    }
+
     /// Returns a Currency given an ISO-4217 currency code as a string.
     pub fn from_string(code: String) -> Result<&'static Currency, MoneyError> {
         if code.chars().all(char::is_alphabetic) {
@@ -71,6 +103,48 @@ impl Currency {
         }
     }
 
+    /// Returns the Currency written with a symbol, using a locale hint when several share it.
+    ///
+    /// Symbols like "$" are ambiguous, so the currency whose default locale is the hint wins
+    /// (e.g. CAD for EnCa and USD for EnUs). Without a matching hint, a symbol used by a single
+    /// currency returns it, and an ambiguous one returns the currency of the EnUs locale, which
+    /// is the default of most currencies. Unknown symbols return None.
+    pub fn resolve_symbol(symbol: &str, locale: Option<Locale>) -> Option<&'static Currency> {
+        let candidates: Vec<&'static Currency> = Currency::iter()
+            .filter(|currency| currency.symbol == symbol)
+            .collect();
+        let in_locale = |locale: Locale| {
+            candidates
+                .iter()
+                .find(|currency| currency.default_locale == locale)
+                .copied()
+        };
+
+        if let Some(currency) = locale.and_then(in_locale) {
+            return Some(currency);
+        }
+        match candidates.as_slice() {
+            [currency] => Some(currency),
+            _ => in_locale(Locale::EnUs),
+        }
+    }
+
+    /// Returns the number of decimal places of the currency's minor unit (e.g. 2 for USD).
+    pub fn exponent(&self) -> u32 {
+        self.exponent
+    }
+
+    /// Returns the size of the currency's minor unit (e.g. 0.01 for USD, 1 for JPY and 0.001
+    /// for BHD), which suits the step of a numeric input.
+    pub fn minor_unit_step(&self) -> Decimal {
+        Decimal::new(1, self.exponent)
+    }
+
+    /// Returns every supported Currency, in the order of the Iso enumeration.
+    pub(crate) fn iter() -> impl Iterator<Item = &'static Currency> {
+        iso::ISO_CURRENCIES.iter().map(|code| Currency::get(*code))
+    }
+
     /// Returns a Currency Hashmap, keyed by ISO alphabetic code.
     fn generate_currencies_by_alpha_code() -> HashMap<String, Currency> {
         let mut num_map: HashMap<String, Currency> = HashMap::new();
@@ -125,4 +199,81 @@ mod tests {
     fn currency_get() {
         assert_eq!(Currency::get(USD), Currency::find("USD").unwrap());
     }
+
+    #[test]
+    fn currency_exponent_and_minor_unit_step() {
+        assert_eq!(Currency::get(USD).exponent(), 2);
+        assert_eq!(Currency::get(USD).minor_unit_step().to_string(), "0.01");
+        assert_eq!(Currency::get(JPY).exponent(), 0);
+        assert_eq!(Currency::get(JPY).minor_unit_step().to_string(), "1");
+        assert_eq!(Currency::get(BHD).exponent(), 3);
+        assert_eq!(Currency::get(BHD).minor_unit_step().to_string(), "0.001");
+    }
+
+    #[test]
+    fn currency_get_by_code() {
+        assert_eq!(Currency::get_by_code("USD").unwrap(), Currency::get(USD));
+        assert_eq!(Currency::get_by_code("JPY").unwrap(), Currency::get(JPY));
+        assert_eq!(
+            Currency::get_by_code("FAKE").unwrap_err(),
+            MoneyError::MalformedCurrencyCode
+        );
+        assert_eq!(
+            Currency::get_by_code("840").unwrap_err(),
+            MoneyError::MalformedCurrencyCode
+        );
+        assert_eq!(
+            Currency::get_by_code("usd").unwrap_err(),
+            MoneyError::MalformedCurrencyCode
+        );
+        assert_eq!(
+            Currency::get_by_code("XYZ").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn currency_is_valid_code_format() {
+        assert!(Currency::is_valid_code_format("USD"));
+        assert!(Currency::is_valid_code_format("XYZ"));
+        assert!(!Currency::is_valid_code_format("usd"));
+        assert!(!Currency::is_valid_code_format("US"));
+        assert!(!Currency::is_valid_code_format("USDX"));
+        assert!(!Currency::is_valid_code_format("840"));
+        assert!(!Currency::is_valid_code_format("ÄBC"));
+        assert!(!Currency::is_valid_code_format(""));
+    }
+
+    #[test]
+    fn currency_resolve_symbol_with_locale_hint() {
+        let usd = Currency::get(USD);
+        let cad = Currency::get(CAD);
+
+        assert_eq!(Currency::resolve_symbol("$", Some(Locale::EnCa)), Some(cad));
+        assert_eq!(Currency::resolve_symbol("$", Some(Locale::EnUs)), Some(usd));
+        assert_eq!(Currency::resolve_symbol("$", None), Some(usd));
+        assert_eq!(Currency::resolve_symbol("$", Some(Locale::FrFr)), Some(usd));
+
+        // Unambiguous symbols ignore the hint
+        let eur = Currency::get(EUR);
+        assert_eq!(Currency::resolve_symbol("€", Some(Locale::EnCa)), Some(eur));
+        assert_eq!(Currency::resolve_symbol("€", None), Some(eur));
+
+        assert_eq!(Currency::resolve_symbol("¤", Some(Locale::EnUs)), None);
+    }
+
+    #[test]
+    fn iso_codes_as_strs() {
+        let codes = Iso::all_codes();
+        assert_eq!(codes.len(), iso::ISO_CURRENCIES.len());
+        assert_eq!(&codes[..3], &["AED", "BHD", "BRL"]);
+        assert!(codes.contains(&"USD"));
+
+        for code in codes {
+            assert_eq!(Iso::from_code(code).unwrap().to_string(), code);
+        }
+        assert_eq!(Iso::from_code("EUR"), Some(EUR));
+        assert_eq!(Iso::from_code("eur"), None);
+        assert_eq!(Iso::from_code("FAKE"), None);
+    }
 }
@@ -1,12 +1,13 @@
 use crate::currency::Currency;
 use crate::locale::Locale;
+use rust_decimal::Decimal;
 use std::fmt;
 
 // Allows iterating over the Iso Enum
 macro_rules! define_enum {
     ($Name:ident { $($Variant:ident),* $(,)* }) =>
     {
-        #[derive(Debug)]
+        #[derive(Debug, PartialEq, Eq, Copy, Clone)]
         pub enum $Name {
             $($Variant),*,
         }
@@ -18,9 +19,13 @@ macro_rules! define_enum {
 define_enum!(Iso {
     AED,
     BHD,
+    BRL,
+    CAD,
+    CHF,
     EUR,
     GBP,
     INR,
+    JPY,
     USD,
 });
 
@@ -30,6 +35,24 @@ impl fmt::Display for Iso {
     }
 }
 
+impl Iso {
+    /// Returns the alphabetic code of every Iso currency, in the order of the enumeration.
+    pub fn all_codes() -> Vec<&'static str> {
+        ISO_CURRENCIES
+            .iter()
+            .map(|code| from_enum(code).iso_alpha_code)
+            .collect()
+    }
+
+    /// Returns the Iso currency given its alphabetic code (e.g. "USD"), which must be upper case.
+    pub fn from_code(code: &str) -> Option<Iso> {
+        ISO_CURRENCIES
+            .iter()
+            .copied()
+            .find(|iso| from_enum(iso).iso_alpha_code == code)
+    }
+}
+
 /// Returns Currency given an Iso Enum.
 pub fn from_enum(code: &Iso) -> Currency {
     use Iso::*;
@@ -37,58 +60,154 @@ pub fn from_enum(code: &Iso) -> Currency {
 
     match code {
         AED => Currency {
+            cash_rounding: None,
             default_locale: EnUs,
             exponent: 2,
+            international_symbol: None,
             iso_alpha_code: "AED",
             iso_numeric_code: "784",
+            major_unit_name: "dirham",
+            minor_unit_name: "fils",
             name: "United Arab Emirates Dirham",
+            rounding_strategy: None,
             symbol: "د.إ",
             symbol_first: false,
+            symbol_spacing: false,
         },
         BHD => Currency {
+            cash_rounding: None,
             default_locale: EnUs,
             exponent: 3,
+            international_symbol: None,
             iso_alpha_code: "BHD",
             iso_numeric_code: "048",
+            major_unit_name: "dinar",
+            minor_unit_name: "fils",
             name: "Bahraini Dinar",
+            rounding_strategy: None,
             symbol: "ب.د",
             symbol_first: true,
+            symbol_spacing: false,
+        },
+        BRL => Currency {
+            cash_rounding: None,
+            default_locale: EnEu,
+            exponent: 2,
+            international_symbol: None,
+            iso_alpha_code: "BRL",
+            iso_numeric_code: "986",
+            major_unit_name: "real",
+            minor_unit_name: "centavo",
+            name: "Brazilian Real",
+            rounding_strategy: None,
+            symbol: "R$",
+            symbol_first: true,
+            symbol_spacing: true,
+        },
+        CAD => Currency {
+            cash_rounding: Some(Decimal::new(5, 2)),
+            default_locale: EnCa,
+            exponent: 2,
+            international_symbol: Some("CA$"),
+            iso_alpha_code: "CAD",
+            iso_numeric_code: "124",
+            major_unit_name: "dollar",
+            minor_unit_name: "cent",
+            name: "Canadian Dollar",
+            rounding_strategy: None,
+            symbol: "$",
+            symbol_first: true,
+            symbol_spacing: false,
+        },
+        CHF => Currency {
+            cash_rounding: Some(Decimal::new(5, 2)),
+            default_locale: EnUs,
+            exponent: 2,
+            international_symbol: None,
+            iso_alpha_code: "CHF",
+            iso_numeric_code: "756",
+            major_unit_name: "franc",
+            minor_unit_name: "centime",
+            name: "Swiss Franc",
+            rounding_strategy: None,
+            symbol: "Fr.",
+            symbol_first: true,
+            symbol_spacing: false,
         },
         EUR => Currency {
+            cash_rounding: None,
             default_locale: EnEu,
             exponent: 2,
+            international_symbol: None,
             iso_alpha_code: "EUR",
             iso_numeric_code: "978",
+            major_unit_name: "euro",
+            minor_unit_name: "cent",
             name: "Euro",
+            rounding_strategy: None,
             symbol: "€",
             symbol_first: true,
+            symbol_spacing: false,
         },
         GBP => Currency {
+            cash_rounding: None,
             default_locale: EnUs,
             exponent: 2,
+            international_symbol: None,
             iso_alpha_code: "GBP",
             iso_numeric_code: "826",
+            major_unit_name: "pound",
+            minor_unit_name: "penny",
             name: "British Pound",
+            rounding_strategy: None,
             symbol: "£",
             symbol_first: true,
+            symbol_spacing: false,
         },
         INR => Currency {
+            cash_rounding: None,
             default_locale: EnIn,
             exponent: 2,
+            international_symbol: None,
             iso_alpha_code: "INR",
             iso_numeric_code: "356",
+            major_unit_name: "rupee",
+            minor_unit_name: "paisa",
             name: "Indian Rupee",
+            rounding_strategy: None,
             symbol: "₹",
             symbol_first: true,
+            symbol_spacing: false,
+        },
+        JPY => Currency {
+            cash_rounding: None,
+            default_locale: EnUs,
+            exponent: 0,
+            international_symbol: None,
+            iso_alpha_code: "JPY",
+            iso_numeric_code: "392",
+            major_unit_name: "yen",
+            minor_unit_name: "sen",
+            name: "Japanese Yen",
+            rounding_strategy: None,
+            symbol: "¥",
+            symbol_first: true,
+            symbol_spacing: false,
         },
         USD => Currency {
+            cash_rounding: None,
             default_locale: EnUs,
             exponent: 2,
+            international_symbol: Some("US$"),
             iso_alpha_code: "USD",
             iso_numeric_code: "840",
+            major_unit_name: "dollar",
+            minor_unit_name: "cent",
             name: "United States Dollar",
+            rounding_strategy: None,
             symbol: "$",
             symbol_first: true,
+            symbol_spacing: false,
         },
     }
 }
@@ -1,18 +1,54 @@
 use std::{error, fmt};
 
+/// A Result whose error is always a `MoneyError`.
+///
+/// ```edition2018
+/// use rusty_money::Money;
+///
+/// fn parse_usd(amount: &str) -> rusty_money::Result<Money> {
+///     Money::from_str(amount, "USD")
+/// }
+///
+/// assert!(parse_usd("2,000.00").is_ok());
+/// ```
+pub type Result<T> = std::result::Result<T, MoneyError>;
+
 #[derive(Debug, PartialEq)]
 pub enum MoneyError {
     InvalidCurrency,
+    CurrencyNotAllowed,
+    MalformedCurrencyCode,
     InvalidAmount,
+    NegativeAmount,
     InvalidRatio,
+    InvalidIncrement,
+    PrecisionExceeded,
+    DivisionByZero,
+    Overflow,
+    MissingExchangeRate {
+        from: &'static str,
+        to: &'static str,
+    },
 }
 
 impl fmt::Display for MoneyError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             MoneyError::InvalidCurrency => write!(f, "Currency was not valid"),
+            MoneyError::CurrencyNotAllowed => write!(f, "Currency was not allowed"),
+            MoneyError::MalformedCurrencyCode => write!(f, "Currency code was malformed"),
             MoneyError::InvalidAmount => write!(f, "Amount not parseable"),
+            MoneyError::NegativeAmount => write!(f, "Amount was negative"),
             MoneyError::InvalidRatio => write!(f, "Ratio was not valid"),
+            MoneyError::InvalidIncrement => write!(f, "Increment was not valid"),
+            MoneyError::PrecisionExceeded => {
+                write!(f, "Amount was more precise than the currency allows")
+            }
+            MoneyError::DivisionByZero => write!(f, "Division by zero"),
+            MoneyError::Overflow => write!(f, "Amount overflowed"),
+            MoneyError::MissingExchangeRate { from, to } => {
+                write!(f, "No exchange rate from {} to {}", from, to)
+            }
         }
     }
 }
@@ -21,8 +57,16 @@ impl error::Error for MoneyError {
     fn description(&self) -> &str {
         match *self {
             MoneyError::InvalidCurrency => "Currency was not valid",
+            MoneyError::CurrencyNotAllowed => "Currency was not allowed",
+            MoneyError::MalformedCurrencyCode => "Currency code was malformed",
             MoneyError::InvalidAmount => "Amount not parseable",
+            MoneyError::NegativeAmount => "Amount was negative",
             MoneyError::InvalidRatio => "Ratio was not valid",
+            MoneyError::InvalidIncrement => "Increment was not valid",
+            MoneyError::PrecisionExceeded => "Amount was more precise than the currency allows",
+            MoneyError::DivisionByZero => "Division by zero",
+            MoneyError::Overflow => "Amount overflowed",
+            MoneyError::MissingExchangeRate { .. } => "No exchange rate was found",
         }
     }
 }
@@ -3,17 +3,52 @@ use crate::money::Money;
 use crate::MoneyError;
 use rust_decimal::Decimal;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::RwLock;
+
+/// A source of exchange rates, which an Exchange consults for rates it does not store.
+///
+/// This is the integration point for external rate feeds (e.g. over HTTP), without the crate
+/// depending on how they are fetched.
+pub trait RateProvider: Send + Sync {
+    /// Returns the rate of conversion from one currency to another, if it is known.
+    fn fetch(&self, from: &Currency, to: &Currency) -> Option<Decimal>;
+}
 
 /// An Exchange Type which stores a collection of exchange rates pairs between currencies.
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct Exchange {
     map: HashMap<String, ExchangeRate>,
+    provider: Option<Box<dyn RateProvider>>,
+    provided: RwLock<HashMap<String, ExchangeRate>>,
+}
+
+impl fmt::Debug for Exchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Exchange")
+            .field("map", &self.map)
+            .field("provider", &self.provider.is_some())
+            .field("provided", &self.provided)
+            .finish()
+    }
 }
 
 impl Exchange {
     pub fn new() -> Exchange {
         Exchange {
             map: HashMap::new(),
+            provider: None,
+            provided: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Creates an Exchange which fetches rates it does not store from a RateProvider.
+    ///
+    /// Fetched rates are cached, so the provider is only asked for each pair once.
+    pub fn with_provider<P: RateProvider + 'static>(provider: P) -> Exchange {
+        Exchange {
+            provider: Some(Box::new(provider)),
+            ..Exchange::new()
         }
     }
 
@@ -23,12 +58,221 @@ impl Exchange {
         self.map.insert(key, *rate);
     }
 
+    /// Adds every rate stored in another Exchange, replacing any stored for the same pair.
+    ///
+    /// Rates don't carry timestamps, so the other Exchange's rates always win. Its provider and
+    /// the rates cached from it are not merged.
+    pub fn merge(&mut self, other: Exchange) {
+        self.map.extend(other.map);
+    }
+
     /// Return the ExchangeRate given the currency pair.
-    pub fn get_rate(self, from: &'static Currency, to: &'static Currency) -> Option<ExchangeRate> {
+    ///
+    /// If the Exchange has a RateProvider, rates which are not stored are fetched from it and
+    /// cached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock on the cache of fetched rates is poisoned.
+    pub fn get_rate(&self, from: &'static Currency, to: &'static Currency) -> Option<ExchangeRate> {
+        if let Some(rate) = self.stored_rate(from, to) {
+            return Some(rate);
+        }
+
+        let provider = self.provider.as_ref()?;
+        let rate = ExchangeRate::new(from, to, provider.fetch(from, to)?).ok()?;
+        self.provided
+            .write()
+            .unwrap()
+            .insert(Exchange::generate_key(from, to), rate);
+        Some(rate)
+    }
+
+    /// Returns a stored rate, or one already fetched from the provider, without fetching it.
+    fn stored_rate(&self, from: &'static Currency, to: &'static Currency) -> Option<ExchangeRate> {
         let key = Exchange::generate_key(from, to);
-        match self.map.get(&key) {
-            Some(v) => Some(*v),
-            None => None,
+        if let Some(rate) = self.map.get(&key) {
+            return Some(*rate);
+        }
+        self.provided.read().unwrap().get(&key).copied()
+    }
+
+    /// Update an ExchangeRate or add it if does not exist, given the ISO-4217 currency codes as strs.
+    pub fn add_rate_by_code(
+        &mut self,
+        from: &str,
+        to: &str,
+        rate: Decimal,
+    ) -> Result<(), MoneyError> {
+        let rate = ExchangeRate::new(Currency::find(from)?, Currency::find(to)?, rate)?;
+        self.add_or_update_rate(&rate);
+        Ok(())
+    }
+
+    /// Return the ExchangeRate given the ISO-4217 currency codes of the pair as strs.
+    pub fn get_rate_by_code(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<Option<ExchangeRate>, MoneyError> {
+        Ok(self.get_rate(Currency::find(from)?, Currency::find(to)?))
+    }
+
+    /// Converts every Money into a single currency using the stored rates, and returns their sum.
+    ///
+    /// Fails with a MissingExchangeRate error naming the currencies of the first item that
+    /// cannot be converted, and with an Overflow error if the sum is too large for a Decimal.
+    pub fn total(&self, items: &[Money], to: &'static Currency) -> Result<Money, MoneyError> {
+        self.fold_total(items.iter().cloned(), to)
+    }
+
+    /// Converts every Money from an iterator into a single currency, and returns their sum.
+    ///
+    /// Unlike `total`, the items don't need to be collected first, and each is converted and
+    /// added as it is read. Fails like `total`, and stops reading at the first error.
+    pub fn fold_total<I: Iterator<Item = Money>>(
+        &self,
+        mut items: I,
+        to: &'static Currency,
+    ) -> Result<Money, MoneyError> {
+        items.try_fold(Money::from_major(0, to), |total, item| {
+            let (converted, _) = self.convert_with_rate(item, to)?;
+            total.checked_add(&converted)
+        })
+    }
+
+    /// Converts two Money into a single currency using the stored rates, and returns their sum.
+    ///
+    /// This is `total` for a pair, and fails with a MissingExchangeRate error in the same way.
+    pub fn add(&self, a: Money, b: Money, into: &'static Currency) -> Result<Money, MoneyError> {
+        self.total(&[a, b], into)
+    }
+
+    /// Converts a Money into another currency, returning the converted Money and the rate applied.
+    ///
+    /// A direct rate for the pair is used when one exists, and otherwise the inverse of
+    /// the rate for the opposite pair. Pairs without either are converted through a pivot
+    /// currency which both have a rate with, applying the product of the two rates. Converting
+    /// into the same currency applies a rate of 1, and a converted amount too large for a
+    /// Decimal raises an Overflow error.
+    pub fn convert_with_rate(
+        &self,
+        money: Money,
+        to: &'static Currency,
+    ) -> Result<(Money, Decimal), MoneyError> {
+        let rate = self.effective_rate(money.currency(), to)?;
+        let converted = Money::from_decimal(*money.amount(), to).checked_mul(rate)?;
+        Ok((converted, rate))
+    }
+
+    /// Converts a Money into another currency and divides it into equal installments.
+    ///
+    /// The converted amount is rounded to the target currency's exponent, then divided into
+    /// shares of whole minor units with `Money::allocate_decimal`, so the installments always sum
+    /// to the rounded amount and the earliest ones take any remainder. Zero parts returns an
+    /// InvalidRatio error.
+    pub fn convert_and_allocate(
+        &self,
+        money: Money,
+        to: &'static Currency,
+        parts: usize,
+    ) -> Result<Vec<Money>, MoneyError> {
+        let (mut converted, _) = self.convert_with_rate(money, to)?;
+        converted.round();
+        converted.allocate_decimal(&vec![Decimal::new(1, 0); parts])
+    }
+
+    /// Converts a Money through each currency in a path in turn, ending in the last one.
+    ///
+    /// Each hop uses a direct or inverse rate like `convert_with_rate`. When `round_hops` is
+    /// true, the amount is rounded to each intermediate currency's exponent as it would be in a
+    /// real conversion, and otherwise full precision is kept until the end. The result itself is
+    /// never rounded. Fails with InvalidCurrency for an empty path.
+    pub fn convert(
+        &self,
+        money: Money,
+        path: &[&'static Currency],
+        round_hops: bool,
+    ) -> Result<Money, MoneyError> {
+        let (last, hops) = path.split_last().ok_or(MoneyError::InvalidCurrency)?;
+        let mut money = money;
+        for hop in hops {
+            money = self.convert_with_rate(money, hop)?.0;
+            if round_hops {
+                money.round();
+            }
+        }
+        Ok(self.convert_with_rate(money, last)?.0)
+    }
+
+    /// Returns the rate the Exchange applies between two currencies (e.g. 0.92 for USD to EUR).
+    ///
+    /// The rate is found like `convert_with_rate` finds it: directly, from the inverse pair, or
+    /// through a pivot currency. Fails with a MissingExchangeRate error when no rate can be found,
+    /// and with an Overflow error when the rate is too large for a Decimal.
+    pub fn effective_rate(
+        &self,
+        from: &'static Currency,
+        to: &'static Currency,
+    ) -> Result<Decimal, MoneyError> {
+        self.find_rate(from, to)?
+            .ok_or(MoneyError::MissingExchangeRate {
+                from: from.iso_alpha_code,
+                to: to.iso_alpha_code,
+            })
+    }
+
+    /// Returns the rate between a pair, computing it from the inverse pair or a pivot if needed.
+    ///
+    /// Pivots are the currencies which both of the pair have a direct or inverse rate with,
+    /// tried in the order of the Iso enumeration. Only the pair itself is fetched from the
+    /// provider, so inverse and pivot rates come from stored and already fetched rates, and a
+    /// missing rate costs a single fetch. A rate too large for a Decimal raises an Overflow error.
+    fn find_rate(
+        &self,
+        from: &'static Currency,
+        to: &'static Currency,
+    ) -> Result<Option<Decimal>, MoneyError> {
+        if from == to {
+            return Ok(Some(Decimal::new(1, 0)));
+        }
+        if let Some(rate) = self.get_rate(from, to) {
+            return rate.unit_rate().map(Some);
+        }
+        if let Some(rate) = self.find_pair_rate(from, to)? {
+            return Ok(Some(rate));
+        }
+        for pivot in Currency::iter().filter(|pivot| *pivot != from && *pivot != to) {
+            let first = match self.find_pair_rate(from, pivot)? {
+                Some(rate) => rate,
+                None => continue,
+            };
+            if let Some(second) = self.find_pair_rate(pivot, to)? {
+                return first
+                    .checked_mul(second)
+                    .ok_or(MoneyError::Overflow)
+                    .map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the stored rate between a pair, computing it from the inverse pair if needed.
+    fn find_pair_rate(
+        &self,
+        from: &'static Currency,
+        to: &'static Currency,
+    ) -> Result<Option<Decimal>, MoneyError> {
+        if let Some(rate) = self.stored_rate(from, to) {
+            return rate.unit_rate().map(Some);
+        }
+        match self.stored_rate(to, from) {
+            Some(rate) if !rate.rate.is_zero() => rate
+                .base
+                .checked_div(rate.rate)
+                .ok_or(MoneyError::Overflow)
+                .map(Some),
+            _ => Ok(None),
         }
     }
 
@@ -43,6 +287,7 @@ pub struct ExchangeRate {
     pub from: &'static Currency,
     pub to: &'static Currency,
     rate: Decimal,
+    base: Decimal,
 }
 
 impl ExchangeRate {
@@ -54,17 +299,65 @@ impl ExchangeRate {
         if from == to {
             return Err(MoneyError::InvalidCurrency);
         }
-        Ok(ExchangeRate { from, to, rate })
+        ExchangeRate::new_per(from, to, rate, Decimal::new(1, 0))
+    }
+
+    /// Creates an ExchangeRate quoted per a base number of units of the source currency.
+    ///
+    /// Rate tables often quote rates per 100 units, so a rate of 110 with a base of 100 means
+    /// 1 USD = 1.10 EUR. A zero base returns a DivisionByZero error.
+    pub fn new_per(
+        from: &'static Currency,
+        to: &'static Currency,
+        rate: Decimal,
+        base: Decimal,
+    ) -> Result<ExchangeRate, MoneyError> {
+        if from == to {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        if base.is_zero() {
+            return Err(MoneyError::DivisionByZero);
+        }
+        Ok(ExchangeRate {
+            from,
+            to,
+            rate,
+            base,
+        })
     }
 
     /// Converts a Money from one Currency to another using the exchange rate.
+    ///
+    /// The converted Money references the rate's `'static` target Currency, so no currency
+    /// data is copied.
     pub fn convert(&self, amount: Money) -> Result<Money, MoneyError> {
         if amount.currency() != self.from {
             return Err(MoneyError::InvalidCurrency);
         }
-        let converted_amount = amount.amount() * self.rate;
+        let converted_amount = amount.amount() * self.rate / self.base;
         Ok(Money::from_decimal(converted_amount, self.to))
     }
+
+    /// Returns the rate for a single unit of the source currency, or an Overflow error when it is
+    /// too large for a Decimal.
+    fn unit_rate(&self) -> Result<Decimal, MoneyError> {
+        self.rate.checked_div(self.base).ok_or(MoneyError::Overflow)
+    }
+
+    /// Converts a Money to the target Currency and back again, rounding to minor units each way.
+    ///
+    /// Comparing the result with the original shows how much is lost to rounding in a
+    /// conversion. A zero rate cannot be reversed and returns DivisionByZero.
+    pub fn round_trip(&self, amount: Money) -> Result<Money, MoneyError> {
+        let mut there = self.convert(amount)?;
+        there.round();
+        let back = (there.amount() * self.base)
+            .checked_div(self.rate)
+            .ok_or(MoneyError::DivisionByZero)?;
+        let mut back = Money::from_decimal(back, self.from);
+        back.round();
+        Ok(back)
+    }
 }
 
 #[cfg(test)]
@@ -86,6 +379,313 @@ mod tests {
         assert_eq!(fetched_rate.rate, dec!(1.5));
     }
 
+    #[test]
+    fn exchange_merge_prefers_the_other_rates() {
+        let usd = Currency::get(USD);
+        let eur = Currency::get(EUR);
+        let gbp = Currency::get(GBP);
+
+        let mut exchange = Exchange::new();
+        exchange.add_rate_by_code("USD", "EUR", dec!(0.9)).unwrap();
+        exchange.add_rate_by_code("USD", "GBP", dec!(0.8)).unwrap();
+
+        let mut other = Exchange::new();
+        other.add_rate_by_code("USD", "EUR", dec!(0.95)).unwrap();
+        other.add_rate_by_code("EUR", "GBP", dec!(0.85)).unwrap();
+
+        exchange.merge(other);
+        assert_eq!(exchange.get_rate(usd, eur).unwrap().rate, dec!(0.95));
+        assert_eq!(exchange.get_rate(usd, gbp).unwrap().rate, dec!(0.8));
+        assert_eq!(exchange.get_rate(eur, gbp).unwrap().rate, dec!(0.85));
+    }
+
+    #[test]
+    fn exchange_fetches_and_caches_rates_from_provider() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct FixedProvider {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl RateProvider for FixedProvider {
+            fn fetch(&self, from: &Currency, to: &Currency) -> Option<Decimal> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                if from.iso_alpha_code == "USD" && to.iso_alpha_code == "EUR" {
+                    Some(dec!(1.5))
+                } else {
+                    None
+                }
+            }
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut exchange = Exchange::with_provider(FixedProvider {
+            calls: calls.clone(),
+        });
+        let usd = Currency::get(USD);
+        let eur = Currency::get(EUR);
+        let gbp = Currency::get(GBP);
+
+        // Missing rates are fetched once, and then cached
+        assert_eq!(exchange.get_rate(usd, eur).unwrap().rate, dec!(1.5));
+        assert_eq!(exchange.get_rate(usd, eur).unwrap().rate, dec!(1.5));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Rates the provider doesn't know stay missing
+        assert_eq!(exchange.get_rate(usd, gbp), None);
+
+        // Stored rates take precedence over the provider
+        exchange.add_rate_by_code("USD", "GBP", dec!(0.8)).unwrap();
+        let calls_before = calls.load(Ordering::SeqCst);
+        assert_eq!(exchange.get_rate(usd, gbp).unwrap().rate, dec!(0.8));
+        assert_eq!(calls.load(Ordering::SeqCst), calls_before);
+    }
+
+    #[test]
+    fn exchange_conversions_only_fetch_the_pair_asked_for() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct EmptyProvider {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl RateProvider for EmptyProvider {
+            fn fetch(&self, _from: &Currency, _to: &Currency) -> Option<Decimal> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                None
+            }
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut exchange = Exchange::with_provider(EmptyProvider {
+            calls: calls.clone(),
+        });
+        let eur = Currency::get(EUR);
+
+        // Inverse and pivot rates are not fetched
+        for _ in 0..3 {
+            assert_eq!(
+                exchange
+                    .convert_with_rate(money!(10, "USD"), eur)
+                    .unwrap_err(),
+                MoneyError::MissingExchangeRate {
+                    from: "USD",
+                    to: "EUR"
+                }
+            );
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // Stored rates are still used inversely and through pivots
+        exchange.add_rate_by_code("EUR", "USD", dec!(2)).unwrap();
+        exchange.add_rate_by_code("GBP", "EUR", dec!(1.25)).unwrap();
+        let (_, rate) = exchange.convert_with_rate(money!(10, "USD"), eur).unwrap();
+        assert_eq!(rate, dec!(0.5));
+        let (_, rate) = exchange
+            .convert_with_rate(money!(10, "USD"), Currency::get(GBP))
+            .unwrap();
+        assert_eq!(rate, dec!(0.4));
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[test]
+    fn exchange_stores_rates_by_code() {
+        let mut exchange = Exchange::new();
+        exchange.add_rate_by_code("USD", "EUR", dec!(1.5)).unwrap();
+
+        let fetched_rate = exchange.get_rate_by_code("USD", "EUR").unwrap().unwrap();
+        assert_eq!(fetched_rate.rate, dec!(1.5));
+        assert_eq!(exchange.get_rate_by_code("EUR", "USD").unwrap(), None);
+
+        // Unknown codes raise InvalidCurrency errors
+        assert_eq!(
+            exchange
+                .add_rate_by_code("USD", "FAKE", dec!(1.5))
+                .unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            exchange.get_rate_by_code("FAKE", "EUR").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn exchange_totals_items_in_one_currency() {
+        let mut exchange = Exchange::new();
+        exchange.add_rate_by_code("EUR", "USD", dec!(1.5)).unwrap();
+        exchange.add_rate_by_code("GBP", "USD", dec!(2)).unwrap();
+
+        let items = vec![money!(10, "USD"), money!(10, "EUR"), money!(10, "GBP")];
+        let total = exchange.total(&items, Currency::get(USD)).unwrap();
+        assert_eq!(total, money!(45, "USD"));
+
+        // An empty list totals to zero
+        let total = exchange.total(&[], Currency::get(USD)).unwrap();
+        assert_eq!(total, money!(0, "USD"));
+
+        // Error names the first item which has no rate
+        let items = vec![money!(10, "EUR"), money!(10, "INR"), money!(10, "AED")];
+        assert_eq!(
+            exchange.total(&items, Currency::get(USD)).unwrap_err(),
+            MoneyError::MissingExchangeRate {
+                from: "INR",
+                to: "USD"
+            }
+        );
+    }
+
+    #[test]
+    fn exchange_converts_along_a_path() {
+        let mut exchange = Exchange::new();
+        exchange
+            .add_rate_by_code("GBP", "USD", dec!(1.3333))
+            .unwrap();
+        exchange.add_rate_by_code("USD", "JPY", dec!(150)).unwrap();
+        exchange.add_rate_by_code("EUR", "JPY", dec!(160)).unwrap();
+        let path = [Currency::get(USD), Currency::get(JPY), Currency::get(EUR)];
+
+        // Full precision: 10.01 GBP -> 13.346333 USD -> 2001.94995 JPY -> 12.5121871875 EUR
+        let converted = exchange
+            .convert(money!("10.01", "GBP"), &path, false)
+            .unwrap();
+        assert_eq!(*converted.amount(), dec!(12.5121871875));
+
+        // Rounded hops: 10.01 GBP -> 13.35 USD -> 2003 JPY -> 12.51875 EUR
+        let converted = exchange
+            .convert(money!("10.01", "GBP"), &path, true)
+            .unwrap();
+        assert_eq!(*converted.amount(), dec!(12.51875));
+
+        // A single hop matches convert_with_rate
+        let converted = exchange
+            .convert(money!(1, "USD"), &[Currency::get(JPY)], true)
+            .unwrap();
+        assert_eq!(converted, money!(150, "JPY"));
+
+        assert_eq!(
+            exchange
+                .convert(money!(1, "USD"), &[Currency::get(INR)], false)
+                .unwrap_err(),
+            MoneyError::MissingExchangeRate {
+                from: "USD",
+                to: "INR"
+            }
+        );
+        assert_eq!(
+            exchange.convert(money!(1, "USD"), &[], false).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn exchange_fold_totals_an_iterator() {
+        let mut exchange = Exchange::new();
+        exchange.add_rate_by_code("EUR", "USD", dec!(1.5)).unwrap();
+
+        let items = (1..=100).map(|i| Money::from_major(i, Currency::get(EUR)));
+        let total = exchange.fold_total(items, Currency::get(USD)).unwrap();
+        assert_eq!(total, money!(7575, "USD"));
+
+        let total = exchange
+            .fold_total(std::iter::empty(), Currency::get(EUR))
+            .unwrap();
+        assert_eq!(total, money!(0, "EUR"));
+
+        // Stops reading at the first item which has no rate
+        let mut items = vec![money!(1, "EUR"), money!(1, "INR"), money!(1, "EUR")].into_iter();
+        assert_eq!(
+            exchange
+                .fold_total(items.by_ref(), Currency::get(USD))
+                .unwrap_err(),
+            MoneyError::MissingExchangeRate {
+                from: "INR",
+                to: "USD"
+            }
+        );
+        assert_eq!(items.len(), 1);
+    }
+
+    #[test]
+    fn exchange_total_overflows() {
+        let mut exchange = Exchange::new();
+        exchange.add_rate_by_code("USD", "EUR", dec!(2)).unwrap();
+        let usd = Currency::get(USD);
+        let max = Money::from_decimal(Decimal::MAX, usd);
+
+        assert_eq!(
+            exchange
+                .total(&[max.clone(), max.clone()], usd)
+                .unwrap_err(),
+            MoneyError::Overflow
+        );
+        assert_eq!(
+            exchange
+                .total(std::slice::from_ref(&max), Currency::get(EUR))
+                .unwrap_err(),
+            MoneyError::Overflow
+        );
+        assert_eq!(
+            exchange
+                .convert_with_rate(max, Currency::get(EUR))
+                .unwrap_err(),
+            MoneyError::Overflow
+        );
+    }
+
+    #[test]
+    fn exchange_convert_with_rate() {
+        let mut exchange = Exchange::new();
+        exchange.add_rate_by_code("USD", "EUR", dec!(2)).unwrap();
+
+        // Direct rates
+        let (converted, rate) = exchange
+            .convert_with_rate(money!(10, "USD"), Currency::get(EUR))
+            .unwrap();
+        assert_eq!(converted, money!(20, "EUR"));
+        assert_eq!(rate, dec!(2));
+
+        // Inverse rates
+        let (converted, rate) = exchange
+            .convert_with_rate(money!(10, "EUR"), Currency::get(USD))
+            .unwrap();
+        assert_eq!(converted, money!(5, "USD"));
+        assert_eq!(rate, dec!(0.5));
+
+        // Pivot rates, through EUR
+        exchange.add_rate_by_code("GBP", "EUR", dec!(2.5)).unwrap();
+        let (converted, rate) = exchange
+            .convert_with_rate(money!(10, "GBP"), Currency::get(USD))
+            .unwrap();
+        assert_eq!(converted, money!("12.5", "USD"));
+        assert_eq!(rate, dec!(1.25));
+        let (converted, rate) = exchange
+            .convert_with_rate(money!(10, "USD"), Currency::get(GBP))
+            .unwrap();
+        assert_eq!(converted, money!(8, "GBP"));
+        assert_eq!(rate, dec!(0.8));
+
+        // Same currency
+        let (converted, rate) = exchange
+            .convert_with_rate(money!(10, "USD"), Currency::get(USD))
+            .unwrap();
+        assert_eq!(converted, money!(10, "USD"));
+        assert_eq!(rate, dec!(1));
+
+        // Error if there is no rate
+        assert_eq!(
+            exchange
+                .convert_with_rate(money!(10, "USD"), Currency::get(JPY))
+                .unwrap_err(),
+            MoneyError::MissingExchangeRate {
+                from: "USD",
+                to: "JPY"
+            }
+        );
+    }
+
     #[test]
     fn rate_convert() {
         let rate = ExchangeRate::new(Currency::get(USD), Currency::get(EUR), dec!(1.5)).unwrap();
@@ -95,6 +695,54 @@ mod tests {
         assert_eq!(converted_rate, expected_amount);
     }
 
+    #[test]
+    fn rate_convert_equals_literal_regardless_of_scale() {
+        let rate = ExchangeRate::new(Currency::get(USD), Currency::get(EUR), dec!(1.1)).unwrap();
+        let converted = rate.convert(money!(1000, "USD")).unwrap();
+        let literal = money!("1.100,00", "EUR");
+
+        assert_ne!(converted.amount().scale(), literal.amount().scale());
+        assert_eq!(converted, literal);
+        assert_eq!(converted, money!(1100, "EUR"));
+        assert_ne!(converted, money!("1.100,01", "EUR"));
+    }
+
+    #[test]
+    fn rate_round_trip() {
+        let rate = ExchangeRate::new(Currency::get(USD), Currency::get(EUR), dec!(0.9137)).unwrap();
+        let original = money!("123.45", "USD");
+        let round_tripped = rate.round_trip(original.clone()).unwrap();
+
+        assert_eq!(round_tripped.currency(), Currency::get(USD));
+        assert!(
+            round_tripped.abs_difference(&original).unwrap()
+                <= Money::one_minor_unit(Currency::get(USD))
+        );
+
+        let rate = ExchangeRate::new(Currency::get(USD), Currency::get(JPY), dec!(149.5)).unwrap();
+        let round_tripped = rate.round_trip(money!("10.01", "USD")).unwrap();
+        assert_eq!(round_tripped, money!("10.01", "USD"));
+
+        let rate = ExchangeRate::new(Currency::get(USD), Currency::get(EUR), dec!(0)).unwrap();
+        assert_eq!(
+            rate.round_trip(money!(1, "USD")).unwrap_err(),
+            MoneyError::DivisionByZero
+        );
+        assert_eq!(
+            rate.round_trip(money!(1, "GBP")).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn rate_convert_references_static_currency() {
+        let eur = Currency::get(EUR);
+        let rate = ExchangeRate::new(Currency::get(USD), eur, dec!(1.5)).unwrap();
+        let converted = rate.convert(money!(10, "USD")).unwrap();
+        assert!(std::ptr::eq(converted.currency(), eur));
+        assert!(std::ptr::eq(rate.to, eur));
+    }
+
     #[test]
     fn rate_convert_errors_if_currencies_dont_match() {
         let rate =
@@ -112,4 +760,227 @@ mod tests {
         let rate = ExchangeRate::new(Currency::get(Iso::GBP), Currency::get(Iso::GBP), dec!(1.5));
         assert_eq!(rate.unwrap_err(), MoneyError::InvalidCurrency,);
     }
+
+    #[test]
+    fn rate_new_per_divides_by_base() {
+        let usd = Currency::get(Iso::USD);
+        let eur = Currency::get(Iso::EUR);
+        let jpy = Currency::get(Iso::JPY);
+
+        let rate = ExchangeRate::new_per(usd, eur, dec!(110), dec!(100)).unwrap();
+        assert_eq!(
+            rate.convert(Money::from_major(1, usd)).unwrap(),
+            Money::from_decimal(dec!(1.10), eur)
+        );
+        assert_eq!(
+            rate.convert(Money::from_major(250, usd)).unwrap(),
+            Money::from_major(275, eur)
+        );
+
+        // Exchanges use the per unit rate, directly and inversely
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(&rate);
+        let (converted, applied) = exchange
+            .convert_with_rate(Money::from_major(10, usd), eur)
+            .unwrap();
+        assert_eq!(converted, Money::from_major(11, eur));
+        assert_eq!(applied, dec!(1.1));
+        let (converted, _) = exchange
+            .convert_with_rate(Money::from_decimal(dec!(1.10), eur), usd)
+            .unwrap();
+        assert_eq!(converted, Money::from_major(1, usd));
+
+        // Yen are commonly quoted per 100
+        let rate = ExchangeRate::new_per(jpy, usd, dec!(0.67), dec!(100)).unwrap();
+        let back = rate.round_trip(Money::from_major(10000, jpy)).unwrap();
+        assert_eq!(back, Money::from_major(10000, jpy));
+
+        assert_eq!(
+            ExchangeRate::new_per(usd, eur, dec!(110), dec!(0)).unwrap_err(),
+            MoneyError::DivisionByZero
+        );
+        assert_eq!(
+            ExchangeRate::new_per(usd, usd, dec!(110), dec!(100)).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn exchange_add_converts_both_operands() {
+        let usd = Currency::get(Iso::USD);
+        let eur = Currency::get(Iso::EUR);
+        let gbp = Currency::get(Iso::GBP);
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(&ExchangeRate::new(usd, gbp, dec!(0.8)).unwrap());
+        exchange.add_or_update_rate(&ExchangeRate::new(eur, gbp, dec!(0.85)).unwrap());
+
+        assert_eq!(
+            exchange
+                .add(money!(10, "USD"), money!(20, "EUR"), gbp)
+                .unwrap(),
+            Money::from_major(25, gbp)
+        );
+
+        // Operands already in the target currency are added as they are
+        assert_eq!(
+            exchange
+                .add(money!(10, "USD"), money!(2, "GBP"), gbp)
+                .unwrap(),
+            Money::from_major(10, gbp)
+        );
+
+        // Inverse rates are used when converting into the source currency
+        assert_eq!(
+            exchange
+                .add(money!(10, "USD"), money!(8, "GBP"), usd)
+                .unwrap(),
+            Money::from_major(20, usd)
+        );
+
+        assert_eq!(
+            exchange
+                .add(money!(10, "USD"), money!(1000, "JPY"), usd)
+                .unwrap_err(),
+            MoneyError::MissingExchangeRate {
+                from: "JPY",
+                to: "USD"
+            }
+        );
+    }
+
+    #[test]
+    fn exchange_effective_rate() {
+        let usd = Currency::get(Iso::USD);
+        let eur = Currency::get(Iso::EUR);
+        let gbp = Currency::get(Iso::GBP);
+        let jpy = Currency::get(Iso::JPY);
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(&ExchangeRate::new(usd, eur, dec!(0.92)).unwrap());
+        exchange.add_or_update_rate(&ExchangeRate::new(gbp, usd, dec!(1.25)).unwrap());
+
+        assert_eq!(exchange.effective_rate(usd, eur).unwrap(), dec!(0.92));
+        assert_eq!(exchange.effective_rate(usd, gbp).unwrap(), dec!(0.8));
+        assert_eq!(exchange.effective_rate(usd, usd).unwrap(), dec!(1));
+
+        // Derived through USD, matching the rate a conversion applies
+        assert_eq!(exchange.effective_rate(gbp, eur).unwrap(), dec!(1.15));
+        assert_eq!(
+            exchange
+                .convert_with_rate(Money::from_major(100, gbp), eur)
+                .unwrap(),
+            (Money::from_major(115, eur), dec!(1.15))
+        );
+        assert_eq!(
+            exchange.total(&[Money::from_major(100, gbp)], eur).unwrap(),
+            Money::from_major(115, eur)
+        );
+        assert_eq!(
+            exchange
+                .convert(Money::from_major(100, gbp), &[usd, eur], false)
+                .unwrap(),
+            Money::from_major(115, eur)
+        );
+
+        assert_eq!(
+            exchange.effective_rate(usd, jpy).unwrap_err(),
+            MoneyError::MissingExchangeRate {
+                from: "USD",
+                to: "JPY"
+            }
+        );
+    }
+
+    #[test]
+    fn exchange_effective_rate_overflows() {
+        let usd = Currency::get(Iso::USD);
+        let eur = Currency::get(Iso::EUR);
+        let gbp = Currency::get(Iso::GBP);
+        let jpy = Currency::get(Iso::JPY);
+        let mut exchange = Exchange::new();
+
+        // Inverse rates
+        exchange.add_or_update_rate(
+            &ExchangeRate::new_per(usd, eur, Decimal::new(1, 28), dec!(100)).unwrap(),
+        );
+        assert_eq!(
+            exchange.effective_rate(eur, usd).unwrap_err(),
+            MoneyError::Overflow
+        );
+
+        // Rates per a tiny base
+        exchange.add_or_update_rate(
+            &ExchangeRate::new_per(usd, jpy, Decimal::MAX, dec!(0.01)).unwrap(),
+        );
+        assert_eq!(
+            exchange
+                .convert_with_rate(Money::from_major(1, usd), jpy)
+                .unwrap_err(),
+            MoneyError::Overflow
+        );
+
+        // Pivot rates, through GBP
+        let mut exchange = Exchange::new();
+        let large = Decimal::from(10_u64.pow(15));
+        exchange.add_or_update_rate(&ExchangeRate::new(usd, gbp, large).unwrap());
+        exchange.add_or_update_rate(&ExchangeRate::new(gbp, eur, large).unwrap());
+        assert_eq!(exchange.effective_rate(usd, gbp).unwrap(), large);
+        assert_eq!(
+            exchange.effective_rate(usd, eur).unwrap_err(),
+            MoneyError::Overflow
+        );
+    }
+
+    #[test]
+    fn exchange_convert_and_allocate() {
+        let usd = Currency::get(Iso::USD);
+        let eur = Currency::get(Iso::EUR);
+        let jpy = Currency::get(Iso::JPY);
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(&ExchangeRate::new(usd, eur, dec!(0.92)).unwrap());
+        exchange.add_or_update_rate(&ExchangeRate::new(usd, jpy, dec!(149.5)).unwrap());
+
+        // 100.01 USD is 92.0092 EUR, rounded to 92.01 EUR
+        let installments = exchange
+            .convert_and_allocate(Money::from_decimal(dec!(100.01), usd), eur, 4)
+            .unwrap();
+        assert_eq!(
+            installments,
+            vec![
+                Money::from_decimal(dec!(23.01), eur),
+                Money::from_decimal(dec!(23.00), eur),
+                Money::from_decimal(dec!(23.00), eur),
+                Money::from_decimal(dec!(23.00), eur),
+            ]
+        );
+
+        // Installments are whole minor units of the target currency
+        let installments = exchange
+            .convert_and_allocate(Money::from_major(10, usd), jpy, 4)
+            .unwrap();
+        assert_eq!(
+            installments,
+            vec![
+                Money::from_major(374, jpy),
+                Money::from_major(374, jpy),
+                Money::from_major(374, jpy),
+                Money::from_major(373, jpy),
+            ]
+        );
+
+        assert_eq!(
+            exchange
+                .convert_and_allocate(Money::from_major(10, usd), eur, 0)
+                .unwrap_err(),
+            MoneyError::InvalidRatio
+        );
+        assert_eq!(
+            exchange
+                .convert_and_allocate(Money::from_major(10, usd), Currency::get(Iso::GBP), 4)
+                .unwrap_err(),
+            MoneyError::MissingExchangeRate {
+                from: "USD",
+                to: "GBP"
+            }
+        );
+    }
 }
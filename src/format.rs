@@ -1,32 +1,64 @@
 use crate::locale::Locale;
 use crate::Money;
+use std::fmt;
 
 pub struct Formatter;
 
 impl Formatter {
     pub fn money(money: &Money, params: Params) -> String {
+        Formatter::money_with(money, &params)
+    }
+
+    /// Returns a formatted Money string, borrowing the params so they can be reused.
+    pub(crate) fn money_with(money: &Money, params: &Params) -> String {
+        let mut result = String::new();
+        Formatter::write_money(&mut result, money, params)
+            .expect("writing to a String never fails");
+        result
+    }
+
+    /// Writes a formatted Money into a writer, instead of allocating a String for it.
+    pub(crate) fn write_money<W: fmt::Write>(
+        w: &mut W,
+        money: &Money,
+        params: &Params,
+    ) -> fmt::Result {
         // Round the decimal
         let mut decimal = *money.amount();
 
         if let Some(x) = params.rounding {
-            decimal = decimal.round_dp(x);
+            decimal =
+                decimal.round_dp_with_strategy(x, money.rounding_strategy().decimal_strategy());
+        }
+
+        if let Some(x) = params.display_exponent {
+            decimal.rescale(x);
+        }
+
+        if let Some(placeholder) = params.zero_display.as_ref().filter(|_| decimal.is_zero()) {
+            return w.write_str(placeholder);
         }
 
         // Format the Amount String
-        let amount = Formatter::amount(&format!("{}", decimal), &params);
+        let amount = Formatter::amount(&format!("{}", decimal), params);
 
         // Position values in the Output String
-        let mut result = String::new();
         for position in params.positions.iter() {
             match position {
-                Position::Space => result.push_str(" "),
-                Position::Amount => result.push_str(&amount),
-                Position::Code => result.push_str(params.code.unwrap_or("")),
-                Position::Symbol => result.push_str(params.symbol.unwrap_or("")),
-                Position::Sign => result.push_str(if money.is_negative() { "-" } else { "" }),
+                Position::Space => w.write_char(' ')?,
+                Position::Amount => w.write_str(&amount)?,
+                Position::Code => w.write_str(params.code.unwrap_or(""))?,
+                Position::Symbol => w.write_str(params.symbol.unwrap_or(""))?,
+                Position::Sign => {
+                    if money.is_negative() {
+                        w.write_char('-')?;
+                    } else if params.force_sign && !decimal.is_zero() {
+                        w.write_char('+')?;
+                    }
+                }
             }
         }
-        result
+        Ok(())
     }
 
     /// Returns a formatted amount string, given the raw amount and params.rust_decimal
@@ -56,16 +88,27 @@ impl Formatter {
     }
 
     /// Returns a formatted digit component, given the digit string, separator and pattern of separation.
+    ///
+    /// The pattern's widths are applied from the exponent separator outward, and the last width
+    /// repeats for as many digits as remain (e.g. [3, 2] groups 10000000 as 1,00,00,000).
     fn digits(raw_digits: &str, separator: char, pattern: &[usize]) -> String {
         let mut digits = raw_digits.to_string();
 
         let mut current_position: usize = 0;
-        for position in pattern.iter() {
-            current_position += position;
-            if digits.len() > current_position {
-                digits.insert(digits.len() - current_position, separator);
-                current_position += 1;
+        let mut index = 0;
+        loop {
+            let width = match (pattern.get(index), pattern.last()) {
+                (Some(width), _) => *width,
+                (None, Some(last)) if *last > 0 => *last,
+                _ => break,
+            };
+            current_position += width;
+            if digits.len() <= current_position {
+                break;
             }
+            digits.insert(digits.len() - current_position, separator);
+            current_position += 1;
+            index += 1;
         }
         digits
     }
@@ -87,9 +130,22 @@ pub struct Params {
     pub separator_pattern: Vec<usize>,
     pub positions: Vec<Position>,
     pub rounding: Option<u32>,
+    /// The number of decimals displayed, which can differ from the decimals used for rounding.
+    ///
+    /// Amounts are padded with zeros, or rounded half away from zero, to this many decimals.
+    /// This only affects the formatted string, and never the stored amount or arithmetic.
+    pub display_exponent: Option<u32>,
     pub symbol: Option<&'static str>,
     pub code: Option<&'static str>,
     pub locale: Option<Locale>,
+    /// Whether positive amounts are signed with a `+` (e.g. "+$5.00"), for displaying changes.
+    ///
+    /// Amounts which are zero once rounded are never signed.
+    pub force_sign: bool,
+    /// A placeholder displayed instead of amounts which are zero once rounded (e.g. "—").
+    ///
+    /// The placeholder replaces the whole string, including the symbol and code.
+    pub zero_display: Option<String>,
 }
 
 impl Default for Params {
@@ -97,12 +153,15 @@ impl Default for Params {
         Params {
             digit_separator: ',',
             exponent_separator: '.',
-            separator_pattern: vec![3, 3, 3],
+            separator_pattern: vec![3],
             positions: vec![Position::Sign, Position::Symbol, Position::Amount],
             rounding: None,
+            display_exponent: None,
             symbol: None,
             code: None,
             locale: None,
+            force_sign: false,
+            zero_display: None,
         }
     }
 }
@@ -191,6 +250,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::inconsistent_digit_grouping)]
     fn format_digit_separators_with_custom_sequences() {
         let params = Params {
             separator_pattern: vec![3, 2, 2],
@@ -219,7 +279,73 @@ mod tests {
         assert_eq!("0,", Formatter::money(&money, params.clone()));
     }
 
-    // What if pattern includes a zero or negative number?
+    #[test]
+    #[allow(clippy::inconsistent_digit_grouping)]
+    fn format_digit_separators_repeat_the_last_width() {
+        let params = Params {
+            separator_pattern: vec![4],
+            ..Default::default()
+        };
+
+        let money = Money::from_major(1_2345_6789, Currency::get(USD));
+        assert_eq!("1,2345,6789", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_major(1234_5678_9012_3456_i64, Currency::get(USD));
+        assert_eq!(
+            "1234,5678,9012,3456",
+            Formatter::money(&money, params.clone())
+        );
+
+        let money = Money::from_major(1234, Currency::get(USD));
+        assert_eq!("1234", Formatter::money(&money, params));
+
+        let params = Params {
+            separator_pattern: vec![3, 2],
+            ..Default::default()
+        };
+        let money = Money::from_major(1_00_00_00_000_i64, Currency::get(USD));
+        assert_eq!("1,00,00,00,000", Formatter::money(&money, params));
+
+        // An empty pattern never groups
+        let params = Params {
+            separator_pattern: vec![],
+            ..Default::default()
+        };
+        let money = Money::from_major(1_000_000, Currency::get(USD));
+        assert_eq!("1000000", Formatter::money(&money, params));
+    }
+
+    #[test]
+    fn format_force_sign() {
+        let params = Params {
+            symbol: Some("$"),
+            rounding: Some(2),
+            display_exponent: Some(2),
+            force_sign: true,
+            ..Default::default()
+        };
+
+        let money = Money::from_major(5, Currency::get(USD));
+        assert_eq!("+$5.00", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_major(-5, Currency::get(USD));
+        assert_eq!("-$5.00", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_major(0, Currency::get(USD));
+        assert_eq!("$0.00", Formatter::money(&money, params.clone()));
+
+        // Amounts which round to zero are not signed
+        let money = Money::from_minor(1, Currency::get(USD)) / 10;
+        assert_eq!("$0.00", Formatter::money(&money, params.clone()));
+
+        // Positive amounts are unsigned by default
+        let params = Params {
+            force_sign: false,
+            ..params
+        };
+        let money = Money::from_major(5, Currency::get(USD));
+        assert_eq!("$5.00", Formatter::money(&money, params));
+    }
 
     #[test]
     fn format_rounding() {
@@ -248,4 +374,61 @@ mod tests {
             Formatter::money(&money, params)
         );
     }
+
+    #[test]
+    fn format_display_exponent() {
+        let money = Money::new(1000, Currency::get(USD)) / 3;
+
+        // Display more decimals than the amount is rounded to
+        let params = Params {
+            rounding: Some(0),
+            display_exponent: Some(2),
+            ..Default::default()
+        };
+        assert_eq!("3.00", Formatter::money(&money, params));
+
+        // Display fewer decimals than are stored
+        let params = Params {
+            rounding: Some(4),
+            display_exponent: Some(2),
+            ..Default::default()
+        };
+        assert_eq!("3.33", Formatter::money(&money, params));
+
+        // Integers are padded
+        let money = Money::from_major(1000, Currency::get(USD));
+        let params = Params {
+            display_exponent: Some(2),
+            ..Default::default()
+        };
+        assert_eq!("1,000.00", Formatter::money(&money, params));
+        assert_eq!("1000", money.amount().to_string());
+    }
+
+    #[test]
+    fn format_zero_display() {
+        let params = Params {
+            symbol: Some("$"),
+            rounding: Some(2),
+            zero_display: Some("—".to_string()),
+            ..Default::default()
+        };
+        let zero = Money::new(0, Currency::get(USD));
+        assert_eq!("—", Formatter::money(&zero, params.clone()));
+
+        // Amounts which round to zero use the placeholder too
+        let tiny = Money::from_decimal(rust_decimal_macros::dec!(-0.001), Currency::get(USD));
+        assert_eq!("—", Formatter::money(&tiny, params.clone()));
+
+        // Non-zero amounts are unaffected
+        let money = Money::new(1234, Currency::get(USD));
+        assert_eq!("$12.34", Formatter::money(&money, params));
+
+        // Zero is displayed normally without a placeholder
+        let params = Params {
+            symbol: Some("$"),
+            ..Default::default()
+        };
+        assert_eq!("$0", Formatter::money(&zero, params));
+    }
 }
@@ -70,13 +70,19 @@ mod exchange;
 mod format;
 mod locale;
 mod money;
+mod rounding;
+#[cfg(feature = "serde")]
+mod serialization;
 
 pub use currency::*;
-pub use error::MoneyError;
+pub use error::{MoneyError, Result};
 pub use exchange::*;
 pub use format::*;
 pub use locale::*;
 pub use money::*;
+pub use rounding::*;
+#[cfg(feature = "serde")]
+pub use serialization::*;
 
 #[macro_use]
 extern crate lazy_static;
@@ -1,10 +1,51 @@
-use std::str::FromStr;
+use std::sync::RwLock;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+lazy_static! {
+    static ref GLOBAL_LOCALE: RwLock<Option<Locale>> = RwLock::new(None);
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum Locale {
     EnUs,
+    EnCa,
     EnIn,
     EnEu,
+    DeDe,
+    FrFr,
+    NlNl,
+}
+
+impl Locale {
+    /// Returns the digit separator and the exponent separator of the locale (e.g. (',', '.') for EnUs).
+    pub fn separators(&self) -> (char, char) {
+        let format = LocalFormat::from_locale(*self);
+        (format.digit_separator, format.exponent_separator)
+    }
+
+    /// Returns the widths of the digit groups of the locale, starting from the exponent separator.
+    ///
+    /// The last width repeats for the rest of the digits, so EnUs is [3] and EnIn is [3, 2].
+    pub fn grouping_pattern(&self) -> &'static [usize] {
+        LocalFormat::from_locale(*self).digit_separator_pattern
+    }
+}
+
+/// Sets a Locale that every Money is displayed in, instead of its currency's default locale.
+///
+/// The setting applies to the whole process. Formatting with an explicit locale through
+/// `Money::format_in` always takes precedence over it.
+pub fn set_global_locale(locale: Locale) {
+    *GLOBAL_LOCALE.write().unwrap() = Some(locale);
+}
+
+/// Clears the global Locale, so that Money is displayed in its currency's default locale again.
+pub fn clear_global_locale() {
+    *GLOBAL_LOCALE.write().unwrap() = None;
+}
+
+/// Returns the global Locale, if one has been set.
+pub fn global_locale() -> Option<Locale> {
+    *GLOBAL_LOCALE.read().unwrap()
 }
 
 /// The `LocalFormat` type
@@ -14,16 +55,22 @@ pub enum Locale {
 pub struct LocalFormat {
     pub name: &'static str,
     pub digit_separator: char,
-    pub digit_separator_pattern: &'static str,
+    pub digit_separator_pattern: &'static [usize],
     pub exponent_separator: char,
+    /// Whether the minus sign of negative amounts precedes the symbol (-$5) or follows it ($-5).
+    pub sign_before_symbol: bool,
+    /// Whether the symbol precedes the amount, overriding the currency's placement when set.
+    pub symbol_first: Option<bool>,
+    /// Whether a space separates the symbol from the amount (e.g. 2.000,01 €), overriding the
+    /// currency's spacing when set.
+    pub symbol_spacing: Option<bool>,
 }
 
 /// Returns LocalFormat given the Locale.
 impl LocalFormat {
     /// Returns a vector indicating where digit separators should be applied for a given currency.  
     pub fn digit_separator_pattern(&self) -> Vec<usize> {
-        let v: Vec<&str> = self.digit_separator_pattern.split(", ").collect();
-        v.iter().map(|x| usize::from_str(x).unwrap()).collect()
+        self.digit_separator_pattern.to_vec()
     }
 
     pub fn from_locale(locale: Locale) -> LocalFormat {
@@ -33,21 +80,88 @@ impl LocalFormat {
             EnUs => LocalFormat {
                 name: "en-us",
                 digit_separator: ',',
-                digit_separator_pattern: "3, 3, 3",
+                digit_separator_pattern: &[3],
                 exponent_separator: '.',
+                sign_before_symbol: true,
+                symbol_first: None,
+                symbol_spacing: None,
+            },
+            EnCa => LocalFormat {
+                name: "en-ca",
+                digit_separator: ',',
+                digit_separator_pattern: &[3],
+                exponent_separator: '.',
+                sign_before_symbol: true,
+                symbol_first: None,
+                symbol_spacing: None,
             },
             EnIn => LocalFormat {
                 name: "en-in",
                 digit_separator: ',',
-                digit_separator_pattern: "3, 2, 2",
+                digit_separator_pattern: &[3, 2],
                 exponent_separator: '.',
+                sign_before_symbol: true,
+                symbol_first: None,
+                symbol_spacing: None,
             },
             EnEu => LocalFormat {
                 name: "en-eu",
                 digit_separator: '.',
-                digit_separator_pattern: "3, 3, 3",
+                digit_separator_pattern: &[3],
+                exponent_separator: ',',
+                sign_before_symbol: true,
+                symbol_first: None,
+                symbol_spacing: None,
+            },
+            DeDe => LocalFormat {
+                name: "de-de",
+                digit_separator: '.',
+                digit_separator_pattern: &[3],
                 exponent_separator: ',',
+                sign_before_symbol: true,
+                symbol_first: Some(false),
+                symbol_spacing: Some(true),
+            },
+            FrFr => LocalFormat {
+                name: "fr-fr",
+                digit_separator: ' ',
+                digit_separator_pattern: &[3],
+                exponent_separator: ',',
+                sign_before_symbol: true,
+                symbol_first: Some(false),
+                symbol_spacing: Some(true),
+            },
+            NlNl => LocalFormat {
+                name: "nl-nl",
+                digit_separator: '.',
+                digit_separator_pattern: &[3],
+                exponent_separator: ',',
+                sign_before_symbol: false,
+                symbol_first: None,
+                symbol_spacing: Some(true),
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_separators() {
+        assert_eq!(Locale::EnUs.separators(), (',', '.'));
+        assert_eq!(Locale::EnCa.separators(), (',', '.'));
+        assert_eq!(Locale::EnIn.separators(), (',', '.'));
+        assert_eq!(Locale::EnEu.separators(), ('.', ','));
+        assert_eq!(Locale::DeDe.separators(), ('.', ','));
+        assert_eq!(Locale::FrFr.separators(), (' ', ','));
+        assert_eq!(Locale::NlNl.separators(), ('.', ','));
+    }
+
+    #[test]
+    fn locale_grouping_pattern() {
+        assert_eq!(Locale::EnUs.grouping_pattern(), &[3]);
+        assert_eq!(Locale::EnIn.grouping_pattern(), &[3, 2]);
+    }
+}
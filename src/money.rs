@@ -1,18 +1,35 @@
 use crate::currency::*;
 use crate::format::{Formatter, Params, Position};
+use crate::locale::global_locale;
+use crate::rounding::RoundingStrategy;
 use crate::MoneyError;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::*;
+use std::cell::RefCell;
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 use std::str::FromStr;
 
+thread_local! {
+    /// The formatter params resolved for each currency and locale, reused by `format_in`.
+    ///
+    /// There are only a handful of pairs in use at once, so a linear scan comparing currency
+    /// pointers is cheaper than hashing.
+    static FORMAT_CACHE: RefCell<Vec<(&'static Currency, Locale, Params)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
 /// The `Money` type, which contains an amount and a currency.
 ///
 /// Money represents financial amounts through a Decimal (owned) and a Currency (refernce).
 /// Operations on Money objects always create new instances of Money, with the exception
 /// of `round()`.
+///
+/// Money objects are equal when their currencies match and their amounts are numerically
+/// equal, regardless of scale, so `1100` and `1100.00` compare equal.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Money {
     amount: Decimal,
@@ -21,11 +38,49 @@ pub struct Money {
 
 /// Create `Money` from an amount and an ISO currency code.
 ///
-/// The amount can be provided as a string or an integer.
+/// The amount can be provided as a string or an integer. Prefix it with `minor` to give it in
+/// minor units instead, so `money!(minor 1234, "USD")` is $12.34 while `money!(1234, "USD")` is
+/// $1,234.
+///
+/// The macro panics if the amount or currency can't be parsed, in debug and release builds
+/// alike. Prefix the arguments with `strict` (e.g. `money!(strict 12, "USD")` or
+/// `money!(strict minor 1234, "USD")`) to accept only literals, so a variable which might not
+/// parse (e.g. user input) is a compile error. The check is made at compile time, so it is the
+/// same in debug and release builds and costs nothing at runtime. Use `try_money!` or
+/// `Money::from_str` for variables, which return a `Result` instead of panicking.
+///
+/// ```compile_fail
+/// let amount = "12.34";
+/// let money = rusty_money::money!(strict amount, "USD");
+/// ```
 #[macro_export]
 macro_rules! money {
+    (strict minor $x:literal, $y:literal) => {
+        $crate::money!(minor $x, $y)
+    };
+    (strict $x:literal, $y:literal) => {
+        $crate::money!($x, $y)
+    };
+    (minor $x:expr, $y:expr) => {
+        $crate::Money::from_minor($x, $crate::Currency::find(&$y.to_string()).unwrap())
+    };
+    ($x:expr, $y:expr) => {
+        $crate::Money::from_string($x.to_string(), $y.to_string()).unwrap()
+    };
+}
+
+/// Create `Money` from an amount and an ISO currency code, returning a `Result`.
+///
+/// Accepts the same arguments as `money!` without `strict`, and returns an InvalidAmount or
+/// InvalidCurrency error instead of panicking, in debug and release builds alike.
+#[macro_export]
+macro_rules! try_money {
+    (minor $x:expr, $y:expr) => {
+        $crate::Currency::find(&$y.to_string())
+            .map(|currency| $crate::Money::from_minor($x, currency))
+    };
     ($x:expr, $y:expr) => {
-        Money::from_string($x.to_string(), $y.to_string()).unwrap();
+        $crate::Money::from_string($x.to_string(), $y.to_string())
     };
 }
 
@@ -147,30 +202,126 @@ impl Ord for Money {
     }
 }
 
+/// Parses Money from an amount and an ISO currency code separated by whitespace.
+///
+/// Both "12.34 USD" and "USD 12.34" are accepted, as are numeric codes in either position
+/// ("12.34 840" or "840 12.34"), and the amount is parsed in the currency's default locale, like
+/// `Money::from_str`. When both parts could be numeric codes (e.g. "840 124"), the trailing
+/// one is the currency.
+impl FromStr for Money {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Money, MoneyError> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        if parts.len() != 2 {
+            return Err(MoneyError::InvalidAmount);
+        }
+
+        let code_first = parts[0].chars().all(char::is_alphabetic)
+            || (Currency::find(parts[1]).is_err() && Currency::find(parts[0]).is_ok());
+        if code_first {
+            Money::from_str(parts[1], parts[0])
+        } else {
+            Money::from_str(parts[0], parts[1])
+        }
+    }
+}
+
+impl From<(Decimal, &'static Currency)> for Money {
+    fn from((amount, currency): (Decimal, &'static Currency)) -> Money {
+        Money::from_decimal(amount, currency)
+    }
+}
+
+impl From<Money> for (Decimal, &'static Currency) {
+    fn from(money: Money) -> (Decimal, &'static Currency) {
+        (money.amount, money.currency)
+    }
+}
+
+/// Compares the amount with an integer, which is taken as major units of the Money's currency.
+impl PartialEq<i64> for Money {
+    fn eq(&self, other: &i64) -> bool {
+        self.amount == Decimal::from(*other)
+    }
+}
+
+/// Compares the amount with an integer, which is taken as major units of the Money's currency.
+impl PartialOrd<i64> for Money {
+    fn partial_cmp(&self, other: &i64) -> Option<Ordering> {
+        self.amount.partial_cmp(&Decimal::from(*other))
+    }
+}
+
+/// Compares the amount with a Decimal, which is taken as major units of the Money's currency.
+impl PartialEq<Decimal> for Money {
+    fn eq(&self, other: &Decimal) -> bool {
+        self.amount == *other
+    }
+}
+
+/// Compares the amount with a Decimal, which is taken as major units of the Money's currency.
+impl PartialOrd<Decimal> for Money {
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        self.amount.partial_cmp(other)
+    }
+}
+
 impl Money {
     /// Creates a Money object given an integer and a currency reference.
     ///
     /// The integer represents minor units of the currency (e.g. 1000 -> 10.00 in USD )
-    pub fn new(amount: i64, currency: &'static Currency) -> Money {
+    pub fn new<T: Into<Decimal>>(amount: T, currency: &'static Currency) -> Money {
         Money::from_minor(amount, currency)
     }
 
     /// Creates a Money object given an integer and a currency reference.
     ///
     /// The integer represents minor units of the currency (e.g. 1000 -> 10.00 in USD )
-    pub fn from_minor(amount: i64, currency: &'static Currency) -> Money {
-        let amount = Decimal::new(amount, currency.exponent);
+    /// and can be of any type that converts into a Decimal, like u32, i64 or usize.
+    pub fn from_minor<T: Into<Decimal>>(amount: T, currency: &'static Currency) -> Money {
+        let amount = amount.into() * Decimal::new(1, currency.exponent);
         Money { amount, currency }
     }
 
     /// Creates a Money object given an integer and a currency reference.
     ///
     /// The integer represents major units of the currency (e.g. 1000 -> 1,000 in USD )
-    pub fn from_major(amount: i64, currency: &'static Currency) -> Money {
-        let amount = Decimal::new(amount, 0);
+    /// and can be of any type that converts into a Decimal, like u32, i64 or usize.
+    pub fn from_major<T: Into<Decimal>>(amount: T, currency: &'static Currency) -> Money {
+        let amount = amount.into();
         Money { amount, currency }
     }
 
+    /// Creates a Money object given a str of minor units and a currency str.
+    ///
+    /// The str must be a whole number, optionally signed (e.g. "1234" -> 12.34 in USD), since
+    /// minor units cannot be fractional.
+    pub fn from_minor_str(amount: &str, currency: &str) -> Result<Money, MoneyError> {
+        let currency = Currency::find(currency)?;
+        let digits = amount.strip_prefix(['-', '+']).unwrap_or(amount);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(MoneyError::InvalidAmount);
+        }
+        let amount = Decimal::from_str(amount).map_err(|_| MoneyError::InvalidAmount)?;
+        Ok(Money::from_minor(amount, currency))
+    }
+
+    /// Creates a Money object given a str of major units and a currency str.
+    ///
+    /// The str must be a plain decimal number with a dot separator and no digit separators
+    /// (e.g. "12.34" -> 12.34 in USD), regardless of the currency's locale.
+    pub fn from_major_str(amount: &str, currency: &str) -> Result<Money, MoneyError> {
+        let currency = Currency::find(currency)?;
+        let amount = Decimal::from_str(amount).map_err(|_| MoneyError::InvalidAmount)?;
+        Ok(Money::from_major(amount, currency))
+    }
+
+    /// Creates a Money object of the smallest unit of a currency (e.g. 0.01 in USD, 1 in JPY).
+    pub fn one_minor_unit(currency: &'static Currency) -> Money {
+        Money::from_minor(1, currency)
+    }
+
     /// Creates a Money object given a decimal amount and a currency reference.
     pub fn from_decimal(amount: Decimal, currency: &'static Currency) -> Money {
         Money { amount, currency }
@@ -178,21 +329,165 @@ impl Money {
 
     /// Creates a Money object given an amount str and a currency str.
     ///
-    /// Supports fuzzy amount strings like "100", "100.00" and "-100.00"
+    /// Supports fuzzy amount strings like "100", "100.00" and "-100.00". This parse is lenient:
+    /// amounts more precise than the currency's exponent are stored in full. Use
+    /// `from_str_strict` to reject them, or `from_str_truncated` to drop the extra digits.
     pub fn from_str(amount: &str, currency: &str) -> Result<Money, MoneyError> {
         Money::from_string(amount.to_string(), currency.to_string())
     }
 
+    /// Returns a MoneyBuilder, for creating Money from separate amount and currency strs.
+    pub fn builder() -> MoneyBuilder {
+        MoneyBuilder::default()
+    }
+
+    /// Creates a Money object given an amount str and a currency str, if the currency is allowed.
+    ///
+    /// The amount is parsed like `Money::from_str`, and a CurrencyNotAllowed error is raised if
+    /// the resolved currency is not in the allowed list.
+    pub fn from_str_allowed(
+        amount: &str,
+        currency: &str,
+        allowed: &[&Currency],
+    ) -> Result<Money, MoneyError> {
+        let money = Money::from_str(amount, currency)?;
+        if !allowed.contains(&money.currency) {
+            return Err(MoneyError::CurrencyNotAllowed);
+        }
+        Ok(money)
+    }
+
+    /// Creates a Money object given a str containing both an amount and a currency.
+    ///
+    /// The currency is inferred from an ISO alphabetic code or a symbol at either end of the
+    /// str (e.g. "USD 1,234.56", "1,234.56 USD", "$1,234.56" or "-$1,234.56"), and the amount is
+    /// parsed in that currency's default locale. Symbols are matched whole, including ones with
+    /// several characters like AED's "د.إ", and when several symbols match, the longest wins.
+    /// Symbols shared by several currencies are resolved like `Currency::resolve_symbol` without
+    /// a hint, so "$" is USD.
+    pub fn from_loose_str(s: &str) -> Result<Money, MoneyError> {
+        let s = s.trim();
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, s),
+        };
+
+        let mut best_match: Option<(&'static Currency, usize, &str)> = None;
+        for currency in Currency::iter() {
+            for marker in &[currency.iso_alpha_code, currency.symbol] {
+                let rest = match strip_marker(unsigned, marker) {
+                    Some(rest) => rest,
+                    None => continue,
+                };
+                if *marker == currency.symbol
+                    && Currency::resolve_symbol(marker, None).is_some_and(|x| x != currency)
+                {
+                    continue;
+                }
+                if best_match.map_or(true, |(_, length, _)| marker.len() > length) {
+                    best_match = Some((currency, marker.len(), rest));
+                }
+            }
+        }
+
+        let (currency, _, amount) = best_match.ok_or(MoneyError::InvalidCurrency)?;
+        let amount = amount.trim();
+        if negative {
+            Money::from_str(&format!("-{}", amount), currency.iso_alpha_code)
+        } else {
+            Money::from_str(amount, currency.iso_alpha_code)
+        }
+    }
+
+    /// Creates a Money object given a str with an optional currency, using a default currency.
+    ///
+    /// A code or symbol is detected like `Money::from_loose_str`, and when the str has none
+    /// (e.g. "1,234.56") it is parsed as an amount in the default currency.
+    pub fn from_str_with_default(s: &str, default: &'static Currency) -> Result<Money, MoneyError> {
+        match Money::from_loose_str(s) {
+            Err(MoneyError::InvalidCurrency) => Money::from_str(s.trim(), default.iso_alpha_code),
+            result => result,
+        }
+    }
+
+    /// Creates a Money object given an amount str and a currency str, rejecting excess precision.
+    ///
+    /// Amounts more precise than the currency's exponent (e.g. "1.001" in USD) raise a
+    /// PrecisionExceeded error instead of being stored. For currencies with an exponent of 0,
+    /// like JPY, this rejects any fractional amount (e.g. "1000.5").
+    pub fn from_str_strict(amount: &str, currency: &str) -> Result<Money, MoneyError> {
+        let money = Money::from_str(amount, currency)?;
+        if money.needs_rounding() {
+            return Err(MoneyError::PrecisionExceeded);
+        }
+        Ok(money)
+    }
+
+    /// Creates a Money object given an amount str and a currency str, truncating excess precision.
+    ///
+    /// Digits beyond the currency's exponent are dropped, rounding toward zero (e.g. "1.009"
+    /// in USD becomes 1.00, and "-1.009" becomes -1.00).
+    pub fn from_str_truncated(amount: &str, currency: &str) -> Result<Money, MoneyError> {
+        let mut money = Money::from_str(amount, currency)?;
+        let strategy = RoundingStrategy::Down.decimal_strategy();
+        money.amount = money
+            .amount
+            .round_dp_with_strategy(money.currency.exponent, strategy);
+        Ok(money)
+    }
+
+    /// Creates a Money object given an amount str and a currency str, capping its precision.
+    ///
+    /// Amounts with more than `max_scale` significant fractional digits raise a
+    /// PrecisionExceeded error, regardless of the currency's exponent (e.g. "1.005" in BHD with
+    /// a `max_scale` of 2). Trailing zeros are not counted, so "1.50" passes a `max_scale` of 1.
+    pub fn from_str_max_scale(
+        amount: &str,
+        currency: &str,
+        max_scale: u32,
+    ) -> Result<Money, MoneyError> {
+        let money = Money::from_str(amount, currency)?;
+        if money.amount.normalize().scale() > max_scale {
+            return Err(MoneyError::PrecisionExceeded);
+        }
+        Ok(money)
+    }
+
     /// Creates a Money object given an amount string and a currency string.
     ///
     /// Supports fuzzy amount strings like "100", "100.00" and "-100.00"
     /// TODO - Consider moving into Formatter
     pub fn from_string(amount: String, currency: String) -> Result<Money, MoneyError> {
         let currency = Currency::from_string(currency)?;
-        let format = LocalFormat::from_locale(currency.default_locale);
+        Money::parse(&amount, currency, currency.default_locale)
+    }
+
+    /// Creates a Money object given an amount str and a currency str, parsed in a Locale.
+    ///
+    /// This parses amounts written with the separators of a locale other than the currency's
+    /// default (e.g. "1 234,56" EUR in FrFr). In locales which group digits with spaces, any
+    /// space is accepted as a separator, including non-breaking spaces.
+    pub fn from_str_in(amount: &str, currency: &str, locale: Locale) -> Result<Money, MoneyError> {
+        Money::parse(amount, Currency::find(currency)?, locale)
+    }
+
+    /// Parses an amount str with the separators of a Locale.
+    fn parse(
+        amount: &str,
+        currency: &'static Currency,
+        locale: Locale,
+    ) -> Result<Money, MoneyError> {
+        let format = LocalFormat::from_locale(locale);
         let amount_parts: Vec<&str> = amount.split(format.exponent_separator).collect();
 
-        let mut parsed_decimal = amount_parts[0].replace(format.digit_separator, "");
+        let mut parsed_decimal: String = if format.digit_separator.is_whitespace() {
+            amount_parts[0]
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect()
+        } else {
+            amount_parts[0].replace(format.digit_separator, "")
+        };
         i32::from_str(&parsed_decimal)?;
 
         if amount_parts.len() == 1 {
@@ -201,16 +496,117 @@ impl Money {
                 parsed_decimal += "0";
             }
         } else if amount_parts.len() == 2 {
-            i32::from_str(&amount_parts[1])?;
+            i32::from_str(amount_parts[1])?;
             parsed_decimal = parsed_decimal + "." + amount_parts[1];
         } else {
             return Err(MoneyError::InvalidAmount);
         }
 
-        let decimal = Decimal::from_str(&parsed_decimal).unwrap();
+        let decimal = Decimal::from_str(&parsed_decimal).map_err(|_| MoneyError::InvalidAmount)?;
         Ok(Money::from_decimal(decimal, currency))
     }
 
+    /// Returns a description of why an amount str and a currency str can't be parsed.
+    ///
+    /// The description is meant for users (e.g. "unknown currency 'XYZ'", "two decimal
+    /// separators" or "non-numeric character 'a' at position 4", counting from 1). The str is
+    /// empty when `Money::from_str` would succeed.
+    pub fn explain_parse_error(amount: &str, currency: &str) -> String {
+        if Money::from_str(amount, currency).is_ok() {
+            return String::new();
+        }
+        let currency = match Currency::find(currency) {
+            Ok(currency) => currency,
+            Err(_) => return format!("unknown currency '{}'", currency),
+        };
+        let format = LocalFormat::from_locale(currency.default_locale);
+
+        if amount.is_empty() {
+            return "no amount".to_string();
+        }
+        for (index, c) in amount.chars().enumerate() {
+            let is_sign = c == '-' || c == '+';
+            if is_sign && index > 0 {
+                return format!(
+                    "sign '{}' at position {} instead of the start",
+                    c,
+                    index + 1
+                );
+            }
+            if !(c.is_ascii_digit()
+                || is_sign
+                || c == format.digit_separator
+                || c == format.exponent_separator)
+            {
+                return format!("non-numeric character '{}' at position {}", c, index + 1);
+            }
+        }
+
+        let parts: Vec<&str> = amount.split(format.exponent_separator).collect();
+        let integer = parts[0].trim_start_matches(['-', '+']);
+        match parts.len() {
+            1 => {}
+            2 if parts[1].is_empty() => {
+                return "no digits after the decimal separator".to_string();
+            }
+            2 if parts[1].contains(format.digit_separator) => {
+                return "digit separator after the decimal separator".to_string();
+            }
+            2 => {}
+            3 => return "two decimal separators".to_string(),
+            n => return format!("{} decimal separators", n - 1),
+        }
+        if integer.chars().all(|c| c == format.digit_separator) {
+            return "no digits before the decimal separator".to_string();
+        }
+        let integer_digits = parts[0].replace(format.digit_separator, "");
+        if parts.len() == 2 && i32::from_str(&integer_digits).is_ok() {
+            return "too many digits after the decimal separator".to_string();
+        }
+        "amount is too large".to_string()
+    }
+
+    /// Parses a batch of amount and currency str pairs, returning a result for each pair.
+    ///
+    /// Each pair is parsed like `Money::from_str`, and a failure does not stop the rest of the
+    /// batch from being parsed.
+    pub fn parse_batch(rows: &[(&str, &str)]) -> Vec<Result<Money, MoneyError>> {
+        rows.iter()
+            .map(|(amount, currency)| Money::from_str(amount, currency))
+            .collect()
+    }
+
+    /// Returns a Money with the same currency and a new amount, leaving this Money unchanged.
+    pub fn with_amount(&self, amount: Decimal) -> Money {
+        Money::from_decimal(amount, self.currency)
+    }
+
+    /// Returns a Money with the same amount relabelled in another currency.
+    ///
+    /// This does NOT convert the value between currencies, and is only meant for correcting
+    /// data which was labelled with the wrong currency. Use an ExchangeRate to convert Money.
+    pub fn with_currency(&self, currency: &'static Currency) -> Money {
+        Money::from_decimal(self.amount, currency)
+    }
+
+    /// Returns an iterator from start to end (inclusive), advancing by step each time.
+    ///
+    /// All three must share a currency, and the step must be positive. The iterator is empty
+    /// if start is greater than end.
+    pub fn range(start: Money, end: Money, step: Money) -> Result<MoneyRange, MoneyError> {
+        if start.currency != end.currency || start.currency != step.currency {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        if !step.is_positive() {
+            return Err(MoneyError::InvalidIncrement);
+        }
+        Ok(MoneyRange {
+            next: Some(start),
+            end: end.amount,
+            step: step.amount,
+        })
+    }
+
     /// Returns a reference to the Decimal amount.
     pub fn amount(&self) -> &Decimal {
         &self.amount
@@ -236,6 +632,25 @@ impl Money {
         self.amount.is_sign_negative() && self.amount != dec!(0.0)
     }
 
+    /// Returns true if the amount is more precise than the currency's exponent.
+    pub fn needs_rounding(&self) -> bool {
+        self.amount.round_dp(self.currency.exponent) != self.amount
+    }
+
+    /// Returns true if the amount is no more precise than the currency's exponent.
+    ///
+    /// This is the complement of `needs_rounding`.
+    pub fn is_exact(&self) -> bool {
+        !self.needs_rounding()
+    }
+
+    /// Returns true if the amount has no fractional part (e.g. $5.00 but not $5.01).
+    ///
+    /// Unlike `is_exact`, this ignores the currency's exponent.
+    pub fn is_whole(&self) -> bool {
+        self.amount.fract().is_zero()
+    }
+
     /// Divides money equally into n shares.
     ///
     /// If the divison cannot be applied perfectly, it allocates the remainder
@@ -292,213 +707,2825 @@ impl Money {
         Ok(allocations)
     }
 
-    /// Rounds the amount down to the currency's exponent.
-    pub fn round(&mut self) {
-        self.amount = self.amount.round_dp(self.currency.exponent);
-    }
-}
+    /// Divides money into shares according to decimal weights (e.g. [0.5, 0.3, 0.2]).
+    ///
+    /// Shares are whole minor units of the currency. Each share is rounded down, and the minor
+    /// units left over go to the shares which lost the most, so the shares always sum to the
+    /// original amount. The weights must be positive, and the amount must not be more precise
+    /// than the currency allows. Amounts or weights too large to divide return an Overflow error.
+    pub fn allocate_decimal(&self, ratios: &[Decimal]) -> Result<Vec<Money>, MoneyError> {
+        if ratios.is_empty() || ratios.iter().any(|ratio| *ratio <= Decimal::ZERO) {
+            return Err(MoneyError::InvalidRatio);
+        }
+        if self.needs_rounding() {
+            return Err(MoneyError::PrecisionExceeded);
+        }
 
-impl fmt::Display for Money {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let currency = self.currency;
-        let format = LocalFormat::from_locale(currency.default_locale);
+        let minor_scale = Decimal::new(10_i64.pow(self.currency.exponent), 0);
+        let units = self
+            .amount
+            .abs()
+            .checked_mul(minor_scale)
+            .ok_or(MoneyError::Overflow)?;
+        let ratio_total = ratios
+            .iter()
+            .try_fold(Decimal::ZERO, |total, ratio| total.checked_add(*ratio))
+            .ok_or(MoneyError::Overflow)?;
+
+        let mut shares = Vec::with_capacity(ratios.len());
+        let mut losses = Vec::with_capacity(ratios.len());
+        for (index, ratio) in ratios.iter().enumerate() {
+            let exact = units
+                .checked_mul(*ratio)
+                .and_then(|weighted| weighted.checked_div(ratio_total))
+                .ok_or(MoneyError::Overflow)?;
+            let share = exact.floor();
+            shares.push(share);
+            losses.push((exact - share, index));
+        }
 
-        let mut format_params = Params {
-            digit_separator: format.digit_separator,
-            exponent_separator: format.exponent_separator,
-            separator_pattern: format.digit_separator_pattern(),
-            rounding: Some(currency.exponent),
-            symbol: Some(currency.symbol),
-            code: Some(currency.iso_alpha_code),
-            locale: Some(currency.default_locale),
-            ..Default::default()
-        };
+        // Hand out the leftover units to the largest losses, earliest first on ties
+        let mut remainder = shares
+            .iter()
+            .try_fold(Decimal::ZERO, |total, share| total.checked_add(*share))
+            .and_then(|allocated| units.checked_sub(allocated))
+            .ok_or(MoneyError::Overflow)?;
+        losses.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        for (_, index) in losses {
+            if remainder <= Decimal::ZERO {
+                break;
+            }
+            shares[index] += Decimal::ONE;
+            remainder -= Decimal::ONE;
+        }
 
-        if currency.symbol_first {
-            format_params.positions = vec![Position::Sign, Position::Symbol, Position::Amount];
-            write!(f, "{}", Formatter::money(self, format_params))
+        let sign = if self.is_negative() {
+            -Decimal::ONE
         } else {
-            format_params.positions = vec![Position::Sign, Position::Amount, Position::Symbol];
-            write!(f, "{}", Formatter::money(self, format_params))
-        }
+            Decimal::ONE
+        };
+        Ok(shares
+            .into_iter()
+            .map(|share| Money::from_decimal(sign * share / minor_scale, self.currency))
+            .collect())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::Iso::*;
+    /// Adds two Money objects of the same currency, without panicking on overflow.
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(amount, self.currency))
+    }
 
-    #[test]
-    fn money_major_minor() {
-        let major_usd = Money::from_major(10, Currency::get(USD));
-        let minor_usd = Money::from_minor(1000, Currency::get(USD));
-        let new_usd = Money::new(1000, Currency::get(USD));
-        assert_eq!(major_usd, minor_usd);
-        assert_eq!(major_usd, new_usd);
+    /// Subtracts a Money object of the same currency, without panicking on overflow.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(amount, self.currency))
     }
 
-    #[test]
-    fn money_from_string_parses_correctly() {
-        let expected_money = Money::new(2999, Currency::get(GBP));
-        let money = Money::from_string("29.99".to_string(), "GBP".to_string()).unwrap();
-        assert_eq!(money, expected_money);
+    /// Subtracts a Money of the same currency, returning zero instead of a negative result.
+    pub fn saturating_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        let difference = self.checked_sub(other)?;
+        if difference.is_negative() {
+            return Ok(Money::from_decimal(Decimal::ZERO, self.currency));
+        }
+        Ok(difference)
     }
 
-    #[test]
-    fn money_from_string_parses_signs() {
-        let expected_money = Money::new(-300, Currency::get(GBP));
-        let money = Money::from_string("-3".to_string(), "GBP".to_string()).unwrap();
-        assert_eq!(money, expected_money);
+    /// Multiplies money by a Decimal, without panicking on overflow.
+    pub fn checked_mul(&self, factor: Decimal) -> Result<Money, MoneyError> {
+        let amount = self
+            .amount
+            .checked_mul(factor)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(amount, self.currency))
+    }
 
-        let expected_money = Money::new(300, Currency::get(GBP));
-        let money = Money::from_string("+3".to_string(), "GBP".to_string()).unwrap();
-        assert_eq!(money, expected_money);
+    /// Returns the change from a baseline Money of the same currency, in Money and in percent.
+    ///
+    /// The percentage is relative to the size of the baseline, so a change from -10 to -5 is
+    /// +50%, and it is not rounded. A zero baseline raises a DivisionByZero error, and
+    /// a percentage too large for a Decimal raises an Overflow error.
+    pub fn compare_to(&self, baseline: &Money) -> Result<PriceChange, MoneyError> {
+        let delta = self.difference(baseline)?;
+        if baseline.is_zero() {
+            return Err(MoneyError::DivisionByZero);
+        }
+        let percent = delta
+            .amount
+            .checked_mul(Decimal::ONE_HUNDRED)
+            .ok_or(MoneyError::Overflow)?
+            .checked_div(baseline.amount.abs())
+            .ok_or(MoneyError::Overflow)?;
+        Ok(PriceChange { delta, percent })
     }
 
-    #[test]
-    fn money_from_string_ignores_separators() {
-        let expected_money = Money::new(100000000, Currency::get(GBP));
-        let money = Money::from_string("1,000,000".to_string(), "GBP".to_string()).unwrap();
-        assert_eq!(money, expected_money);
+    /// Returns whether two Money objects differ by at most a number of minor units.
+    ///
+    /// For USD, `approx_eq_units(other, 1)` is true when they are within a cent. Money objects
+    /// of different currencies are never approximately equal.
+    pub fn approx_eq_units(&self, other: &Money, units: u32) -> bool {
+        let tolerance = Decimal::from(units) * Decimal::new(1, self.currency.exponent);
+        self.abs_difference(other)
+            .is_ok_and(|difference| difference.amount <= tolerance)
     }
 
-    #[test]
-    fn money_from_string_parse_errs() {
-        // If the delimiter preceeds the separators
-        let money = Money::from_string("1.0000,000".to_string(), "GBP".to_string());
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns whether two Money objects are displayed the same once rounded to the exponent.
+    ///
+    /// Amounts are rounded like Display does, so $2.001 and $2.004 are equal as both show
+    /// $2.00. Money objects of different currencies are never equal.
+    pub fn display_eq(&self, other: &Money) -> bool {
+        self.currency == other.currency && self.rounded_amount() == other.rounded_amount()
+    }
 
-        // If there are multiple delimiters
-        let money = Money::from_string("1.0000.000".to_string(), "GBP".to_string());
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Divides money by an integer, returning a DivisionByZero error instead of panicking.
+    ///
+    /// The quotient is not rounded.
+    pub fn try_div(&self, divisor: i32) -> Result<Money, MoneyError> {
+        let amount = self
+            .amount
+            .checked_div(Decimal::from(divisor))
+            .ok_or(MoneyError::DivisionByZero)?;
+        Ok(Money::from_decimal(amount, self.currency))
+    }
 
-        // If there is an unrecognized character
-        let money = Money::from_string("1.0000!000".to_string(), "GBP".to_string());
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns the signed difference between two Money objects of the same currency (self - other).
+    pub fn difference(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.checked_sub(other)
+    }
 
-        // If there are no characters other than separators
-        let exponent_separator_only = Money::from_string(",".to_string(), "GBP".to_string());
-        let amount_separator_only = Money::from_string(".".to_string(), "GBP".to_string());
-        let both_separators = Money::from_string(",,.".to_string(), "GBP".to_string());
-        assert_eq!(
-            exponent_separator_only.unwrap_err(),
-            MoneyError::InvalidAmount
-        );
-        assert_eq!(
-            amount_separator_only.unwrap_err(),
-            MoneyError::InvalidAmount
-        );
-        assert_eq!(both_separators.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns the magnitude of the difference between two Money objects of the same currency.
+    pub fn abs_difference(&self, other: &Money) -> Result<Money, MoneyError> {
+        let difference = self.difference(other)?;
+        Ok(Money::from_decimal(difference.amount.abs(), self.currency))
     }
 
-    #[test]
-    fn money_format_rounds_exponent() {
-        // // 19.999 rounds to 20 for USD
-        let money = money!("19.9999", "USD");
-        assert_eq!("$20.00", format!("{}", money));
+    /// Splits a tax-inclusive amount into its net amount and the tax, given a tax rate (e.g. 0.19).
+    ///
+    /// The net amount is truncated to the currency's exponent and the remainder goes to the tax,
+    /// so that net + tax is always exactly the original amount. Amounts too large to split
+    /// return an Overflow error.
+    pub fn extract_tax(&self, rate: Decimal) -> Result<(Money, Money), MoneyError> {
+        if rate.is_sign_negative() {
+            return Err(MoneyError::InvalidRatio);
+        }
+        let minor_scale = Decimal::new(10_i64.pow(self.currency.exponent), 0);
+        let net = self
+            .amount
+            .checked_mul(minor_scale)
+            .and_then(|units| units.checked_div(dec!(1).checked_add(rate)?))
+            .ok_or(MoneyError::Overflow)?
+            .trunc()
+            / minor_scale;
+        let tax = self.amount.checked_sub(net).ok_or(MoneyError::Overflow)?;
+        Ok((
+            Money::from_decimal(net, self.currency),
+            Money::from_decimal(tax, self.currency),
+        ))
+    }
 
-        // // 29.111 rounds to 29.11 for USD
-        let money = money!("29.111", "USD");
-        assert_eq!("$29.11", format!("{}", money));
+    /// Adds tax to a net amount given a tax rate (e.g. 0.19), returning the tax-inclusive amount.
+    ///
+    /// The tax is rounded to the currency's exponent like `round` does before it is added.
+    /// Amounts too large to tax return an Overflow error.
+    pub fn add_tax(&self, rate: Decimal) -> Result<Money, MoneyError> {
+        if rate.is_sign_negative() {
+            return Err(MoneyError::InvalidRatio);
+        }
+        let strategy = self.rounding_strategy().decimal_strategy();
+        let gross = self
+            .amount
+            .checked_mul(rate)
+            .map(|tax| tax.round_dp_with_strategy(self.currency.exponent, strategy))
+            .and_then(|tax| self.amount.checked_add(tax))
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(gross, self.currency))
+    }
 
-        // // 39.1155 rounds to 39.116 for BHD
-        let money = money!("39.1155", "BHD");
-        assert_eq!("ب.د39.116", format!("{}", money));
+    /// Returns the simple interest earned on this principal at a rate per period (e.g. 0.05).
+    ///
+    /// The interest carries full precision and is not rounded, so callers should round it for
+    /// display. Fails with an Overflow error if the interest can't be represented.
+    pub fn simple_interest(&self, rate: Decimal, periods: u32) -> Result<Money, MoneyError> {
+        let interest = self
+            .amount
+            .checked_mul(rate)
+            .and_then(|x| x.checked_mul(Decimal::from(periods)))
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(interest, self.currency))
     }
 
-    #[test]
-    fn money_addition_and_subtraction() {
-        // Addition
-        assert_eq!(money!(2, "USD"), money!(1, "USD") + money!(1, "USD"));
-        // Subtraction
-        assert_eq!(money!(0, "USD"), money!(1, "USD") - money!(1, "USD"));
+    /// Returns the interest earned on this principal at a rate compounded each period.
+    ///
+    /// Like `simple_interest`, this carries full precision and is not rounded, and fails with
+    /// an Overflow error if the interest can't be represented.
+    pub fn compound_interest(&self, rate: Decimal, periods: u32) -> Result<Money, MoneyError> {
+        let growth = Decimal::ONE + rate;
+        let mut total = self.amount;
+        for _ in 0..periods {
+            total = total.checked_mul(growth).ok_or(MoneyError::Overflow)?;
+        }
+        let interest = total.checked_sub(self.amount).ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(interest, self.currency))
     }
 
-    #[test]
-    fn money_multiplication_and_division() {
-        // Multiplication
+    /// Totals net line items and the VAT on them at a rate (e.g. 0.19), returning (net, VAT).
+    ///
+    /// VAT can legally be rounded in two ways, which can give totals a cent apart. With
+    /// `per_line`, the VAT on each line is rounded half up to the currency's exponent and then
+    /// summed. Otherwise the VAT on the net total is rounded once. All lines must share a
+    /// currency, and mixed currencies return an InvalidCurrency error. There must be at least
+    /// one line to take the currency from, so an empty slice returns an InvalidAmount error.
+    pub fn total_with_vat(
+        lines: &[Money],
+        rate: Decimal,
+        per_line: bool,
+    ) -> Result<(Money, Money), MoneyError> {
+        if rate.is_sign_negative() {
+            return Err(MoneyError::InvalidRatio);
+        }
+        let currency = lines.first().ok_or(MoneyError::InvalidAmount)?.currency;
+        if lines.iter().any(|line| line.currency != currency) {
+            return Err(MoneyError::InvalidCurrency);
+        }
+
+        let strategy = RoundingStrategy::HalfUp.decimal_strategy();
+        let vat_on = |amount: Decimal| -> Result<Decimal, MoneyError> {
+            Ok(amount
+                .checked_mul(rate)
+                .ok_or(MoneyError::Overflow)?
+                .round_dp_with_strategy(currency.exponent, strategy))
+        };
+
+        let mut net = Decimal::ZERO;
+        let mut vat = Decimal::ZERO;
+        for line in lines {
+            net = net.checked_add(line.amount).ok_or(MoneyError::Overflow)?;
+            if per_line {
+                vat = vat
+                    .checked_add(vat_on(line.amount)?)
+                    .ok_or(MoneyError::Overflow)?;
+            }
+        }
+        if !per_line {
+            vat = vat_on(net)?;
+        }
+        Ok((
+            Money::from_decimal(net, currency),
+            Money::from_decimal(vat, currency),
+        ))
+    }
+
+    /// Divides money by an integer, returning the quotient and the remainder.
+    ///
+    /// The quotient is truncated to the currency's minor unit, and the remainder holds
+    /// whatever is left over, so that quotient * divisor + remainder == amount. Amounts too
+    /// large to divide in minor units return an Overflow error.
+    pub fn div_rem(&self, divisor: i32) -> Result<(Money, Money), MoneyError> {
+        if divisor == 0 {
+            return Err(MoneyError::DivisionByZero);
+        }
+
+        let divisor = Decimal::from(divisor);
+        let minor_scale = Decimal::new(10_i64.pow(self.currency.exponent), 0);
+        let quotient = self
+            .amount
+            .checked_mul(minor_scale)
+            .and_then(|units| units.checked_div(divisor))
+            .ok_or(MoneyError::Overflow)?
+            .trunc()
+            / minor_scale;
+        let remainder = quotient
+            .checked_mul(divisor)
+            .and_then(|divided| self.amount.checked_sub(divided))
+            .ok_or(MoneyError::Overflow)?;
+
+        Ok((
+            Money::from_decimal(quotient, self.currency),
+            Money::from_decimal(remainder, self.currency),
+        ))
+    }
+
+    /// Breaks the amount into counts of bills and coins, taking as many of the largest as fit.
+    ///
+    /// Returns each denomination used with its count, from largest to smallest. The amount must
+    /// not be negative or more precise than the currency allows, the denominations must be
+    /// positive, and they must add up to exactly the amount (e.g. 0.03 can't be made from 0.05).
+    /// For unusual sets of denominations, taking the largest first may not use the fewest items.
+    /// Counts too large for a u32 return an Overflow error.
+    pub fn denominations(&self, units: &[Decimal]) -> Result<Vec<(Decimal, u32)>, MoneyError> {
+        if self.is_negative() {
+            return Err(MoneyError::InvalidAmount);
+        }
+        if self.needs_rounding() {
+            return Err(MoneyError::PrecisionExceeded);
+        }
+        if units
+            .iter()
+            .any(|unit| unit.is_sign_negative() || unit.is_zero())
+        {
+            return Err(MoneyError::InvalidIncrement);
+        }
+
+        let mut units = units.to_vec();
+        units.sort_by(|a, b| b.cmp(a));
+        units.dedup();
+
+        let mut remaining = self.amount;
+        let mut counts = Vec::new();
+        for unit in units {
+            let count = remaining
+                .checked_div(unit)
+                .ok_or(MoneyError::Overflow)?
+                .trunc();
+            if count.is_zero() {
+                continue;
+            }
+            remaining = count
+                .checked_mul(unit)
+                .and_then(|used| remaining.checked_sub(used))
+                .ok_or(MoneyError::Overflow)?;
+            counts.push((unit, count.to_u32().ok_or(MoneyError::Overflow)?));
+        }
+
+        if !remaining.is_zero() {
+            return Err(MoneyError::InvalidIncrement);
+        }
+        Ok(counts)
+    }
+
+    /// Sorts a slice of Money in ascending order of amount.
+    ///
+    /// All amounts must share a currency, and the slice is left unchanged if they don't. The sort
+    /// is stable, so equal amounts keep their order.
+    pub fn sort(items: &mut [Money]) -> Result<(), MoneyError> {
+        if let Some(first) = items.first() {
+            let currency = first.currency;
+            if items.iter().any(|item| item.currency != currency) {
+                return Err(MoneyError::InvalidCurrency);
+            }
+        }
+        items.sort_by_key(|item| item.amount);
+        Ok(())
+    }
+
+    /// Rounds each Money to its currency's exponent, so that the results sum to the rounded total.
+    ///
+    /// Rounding each amount individually can make the results drift away from the rounded sum
+    /// of the originals. The difference is redistributed one minor unit at a time to the amounts
+    /// which lost the most when rounded. All amounts must share a currency, and a sum too large
+    /// for a Decimal raises an Overflow error.
+    pub fn round_all_preserving_sum(
+        items: &[Money],
+        strategy: RoundingStrategy,
+    ) -> Result<Vec<Money>, MoneyError> {
+        let currency = match items.first() {
+            Some(item) => item.currency,
+            None => return Ok(Vec::new()),
+        };
+        if items.iter().any(|item| item.currency != currency) {
+            return Err(MoneyError::InvalidCurrency);
+        }
+
+        let exponent = currency.exponent;
+        let minor_unit = Decimal::new(1, exponent);
+        let round =
+            |amount: Decimal| amount.round_dp_with_strategy(exponent, strategy.decimal_strategy());
+
+        let sum = |acc: Decimal, x: &Decimal| acc.checked_add(*x).ok_or(MoneyError::Overflow);
+        let total = round(
+            items
+                .iter()
+                .map(|item| &item.amount)
+                .try_fold(dec!(0.0), sum)?,
+        );
+        let mut rounded: Vec<Decimal> = items.iter().map(|item| round(item.amount)).collect();
+        let rounded_total = rounded.iter().try_fold(dec!(0.0), sum)?;
+        let difference = total
+            .checked_sub(rounded_total)
+            .ok_or(MoneyError::Overflow)?;
+
+        // Amounts which lost the most in rounding are adjusted first.
+        let mut indices: Vec<usize> = (0..items.len()).collect();
+        let errors: Vec<Decimal> = items
+            .iter()
+            .zip(rounded.iter())
+            .map(|(item, rounded)| item.amount - rounded)
+            .collect();
+        let step = if difference > dec!(0.0) {
+            indices.sort_by(|a, b| errors[*b].cmp(&errors[*a]));
+            minor_unit
+        } else {
+            indices.sort_by(|a, b| errors[*a].cmp(&errors[*b]));
+            -minor_unit
+        };
+
+        let steps = (difference / minor_unit).abs().to_usize().unwrap_or(0);
+        for i in indices.iter().cycle().take(steps) {
+            rounded[*i] += step;
+        }
+
+        Ok(rounded
+            .into_iter()
+            .map(|amount| Money::from_decimal(amount, currency))
+            .collect())
+    }
+
+    /// Rounds the amount to the currency's exponent.
+    ///
+    /// The currency's rounding strategy is used, and RoundingStrategy::HalfUp if it has none.
+    pub fn round(&mut self) {
+        self.amount = self.rounded_amount();
+    }
+
+    /// Returns the amount rounded to the currency's exponent, like `round()` does.
+    pub(crate) fn rounded_amount(&self) -> Decimal {
+        self.amount.round_dp_with_strategy(
+            self.currency.exponent,
+            self.rounding_strategy().decimal_strategy(),
+        )
+    }
+
+    /// Returns the amount as an f64, rounded to a number of decimal places first.
+    ///
+    /// This is lossy like any conversion to f64, and is meant for charts and plots, where
+    /// rounding first keeps values like axis labels predictable. The currency's rounding
+    /// strategy is used, like in `round()`.
+    pub fn to_f64_rounded(&self, places: u32) -> f64 {
+        self.amount
+            .round_dp_with_strategy(places, self.rounding_strategy().decimal_strategy())
+            .to_f64()
+            .unwrap_or(f64::NAN)
+    }
+
+    /// Returns the currency's rounding strategy, or RoundingStrategy::HalfUp if it has none.
+    pub(crate) fn rounding_strategy(&self) -> RoundingStrategy {
+        self.currency
+            .rounding_strategy
+            .unwrap_or(RoundingStrategy::HalfUp)
+    }
+
+    /// Rounds the amount to the nearest multiple of an increment, using a RoundingStrategy.
+    ///
+    /// This models cash rounding rules, like rounding CHF to the nearest 0.05. The increment
+    /// must be positive, and amounts too large to round to it return an Overflow error.
+    pub fn round_to_increment(
+        &mut self,
+        increment: Decimal,
+        strategy: RoundingStrategy,
+    ) -> Result<(), MoneyError> {
+        if increment <= dec!(0.0) {
+            return Err(MoneyError::InvalidIncrement);
+        }
+        let increments = self
+            .amount
+            .checked_div(increment)
+            .ok_or(MoneyError::Overflow)?;
+        self.amount = increments
+            .round_dp_with_strategy(0, strategy.decimal_strategy())
+            .checked_mul(increment)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(())
+    }
+
+    /// Rounds the amount according to the currency's cash rounding rules.
+    ///
+    /// Currencies with a cash rounding increment (e.g. 0.05 for CHF) are rounded half up to
+    /// the nearest multiple of it, and other currencies are rounded like `round()`.
+    pub fn round_cash(&mut self) {
+        if let Some(increment) = self.currency.cash_rounding {
+            if self
+                .round_to_increment(increment, RoundingStrategy::HalfUp)
+                .is_ok()
+            {
+                return;
+            }
+        }
+        self.round();
+    }
+
+    /// Returns the amount as a canonical fixed-point string, for storage and transfer.
+    ///
+    /// The string has no symbol or digit separators, uses a dot as the exponent separator, and
+    /// has exactly as many decimals as the currency's exponent, rounding with the given strategy
+    /// (e.g. "1234.56" in USD, "1000" in JPY).
+    pub fn to_canonical_string(&self, strategy: RoundingStrategy) -> String {
+        let exponent = self.currency.exponent;
+        let mut amount = self
+            .amount
+            .round_dp_with_strategy(exponent, strategy.decimal_strategy());
+        amount.rescale(exponent);
+        if amount.is_zero() {
+            amount.set_sign_positive(true);
+        }
+        amount.to_string()
+    }
+
+    /// Returns the amount and alphabetic code for an ISO 20022 CurrencyAndAmount element.
+    ///
+    /// The amount is written like `to_canonical_string` (e.g. ("1234.56", "USD")). Amounts
+    /// which are negative raise a NegativeAmount error, amounts more precise than the currency
+    /// allows raise a PrecisionExceeded error, and amounts of more than 18 digits raise an
+    /// Overflow error, since none of these can be represented.
+    pub fn to_iso20022(&self) -> Result<(String, String), MoneyError> {
+        if self.is_negative() {
+            return Err(MoneyError::NegativeAmount);
+        }
+        if self.needs_rounding() {
+            return Err(MoneyError::PrecisionExceeded);
+        }
+        let amount = self.to_canonical_string(RoundingStrategy::HalfEven);
+        if amount.chars().filter(char::is_ascii_digit).count() > 18 {
+            return Err(MoneyError::Overflow);
+        }
+        Ok((amount, self.currency.iso_alpha_code.to_string()))
+    }
+
+    /// Returns the Money formatted with the separators of a Locale, instead of its currency's default.
+    ///
+    /// The explicit locale always wins over one set with `set_global_locale`. Like Display, this
+    /// rounds the amount for presentation without changing the stored amount.
+    pub fn format_in(&self, locale: Locale) -> String {
+        let mut result = String::new();
+        self.write_to(&mut result, locale)
+            .expect("writing to a String never fails");
+        result
+    }
+
+    /// Writes the Money formatted in a Locale into a writer, like `format_in`.
+    ///
+    /// This avoids allocating a String for each Money when writing many of them into the same
+    /// buffer or stream.
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W, locale: Locale) -> fmt::Result {
+        FORMAT_CACHE.with(|cache| {
+            // The writer could itself format Money, so the cache may already be borrowed
+            let mut cache = match cache.try_borrow_mut() {
+                Ok(cache) => cache,
+                Err(_) => {
+                    let params = self.resolve_params(locale);
+                    return Formatter::write_money(w, self, &params);
+                }
+            };
+            let cached = cache.iter().position(|(currency, cached_locale, _)| {
+                std::ptr::eq(*currency, self.currency) && *cached_locale == locale
+            });
+            let index = match cached {
+                Some(index) => index,
+                None => {
+                    cache.push((self.currency, locale, self.resolve_params(locale)));
+                    cache.len() - 1
+                }
+            };
+            Formatter::write_money(w, self, &cache[index].2)
+        })
+    }
+
+    /// Returns the formatter params for this Money's currency in a locale.
+    fn resolve_params(&self, locale: Locale) -> Params {
+        let format = LocalFormat::from_locale(locale);
+        let positions = self.locale_positions(&format);
+        self.locale_params(locale, &format, positions)
+    }
+
+    /// Returns whether Display or `format_in` would place the symbol before the amount.
+    ///
+    /// The locale is the one that would be formatted in, or None for the global or default
+    /// locale. A locale's placement wins over the currency's.
+    pub fn symbol_first_for(&self, locale: Option<Locale>) -> bool {
+        let locale = locale
+            .or_else(global_locale)
+            .unwrap_or(self.currency.default_locale);
+        LocalFormat::from_locale(locale)
+            .symbol_first
+            .unwrap_or(self.currency.symbol_first)
+    }
+
+    /// Returns the symbol that Display or `format_in` would use for this Money.
+    ///
+    /// The locale is the one that would be formatted in, or None for the global or default
+    /// locale. When `Currency::resolve_symbol` would take the currency's symbol for another
+    /// currency in that locale, its international symbol is used instead, so USD is "$" in EnUs
+    /// but "US$" in EnCa.
+    pub fn display_symbol(&self, locale: Option<Locale>) -> &'static str {
+        let currency = self.currency;
+        let locale = locale
+            .or_else(global_locale)
+            .unwrap_or(currency.default_locale);
+        match currency.international_symbol {
+            Some(symbol)
+                if Currency::resolve_symbol(currency.symbol, Some(locale)) != Some(currency) =>
+            {
+                symbol
+            }
+            _ => currency.symbol,
+        }
+    }
+
+    /// Returns the Money formatted with its symbol and its ISO code (e.g. "$2,000.01 USD").
+    ///
+    /// The symbol is placed as in Display, and the code always follows the amount. Like
+    /// Display, this uses the global locale if one is set.
+    pub fn to_string_symbol_and_code(&self) -> String {
+        let locale = global_locale().unwrap_or(self.currency.default_locale);
+        let format = LocalFormat::from_locale(locale);
+        let mut positions = self.locale_positions(&format);
+        positions.push(Position::Space);
+        positions.push(Position::Code);
+        Formatter::money(self, self.locale_params(locale, &format, positions))
+    }
+
+    /// Returns the Money as HTML, with the sign, symbol and amount each wrapped in a span.
+    ///
+    /// The symbol span uses `symbol_class`, while the sign and amount spans use the `sign` and
+    /// `amount` classes. The sign span is omitted for non-negative amounts, and all text is
+    /// escaped. Like Display, this uses the global locale if one is set.
+    pub fn to_html(&self, symbol_class: &str) -> String {
+        let locale = global_locale().unwrap_or(self.currency.default_locale);
+        let format = LocalFormat::from_locale(locale);
+
+        let mut html = String::new();
+        for position in self.locale_positions(&format) {
+            match position {
+                Position::Space => html.push(' '),
+                Position::Amount => {
+                    let params = self.locale_params(locale, &format, vec![Position::Amount]);
+                    html.push_str(&format!(
+                        "<span class=\"amount\">{}</span>",
+                        escape_html(&Formatter::money(self, params))
+                    ));
+                }
+                Position::Code => html.push_str(&escape_html(self.currency.iso_alpha_code)),
+                Position::Symbol => html.push_str(&format!(
+                    "<span class=\"{}\">{}</span>",
+                    escape_html(symbol_class),
+                    escape_html(self.display_symbol(Some(locale)))
+                )),
+                Position::Sign => {
+                    if self.is_negative() {
+                        html.push_str("<span class=\"sign\">-</span>");
+                    }
+                }
+            }
+        }
+        html
+    }
+
+    /// Returns the positions of the sign, symbol and amount in a locale format.
+    fn locale_positions(&self, format: &LocalFormat) -> Vec<Position> {
+        let mut positions = Vec::new();
+        let symbol_spacing = format
+            .symbol_spacing
+            .unwrap_or(self.currency.symbol_spacing);
+        if format.symbol_first.unwrap_or(self.currency.symbol_first) {
+            if format.sign_before_symbol {
+                positions.push(Position::Sign);
+            }
+            positions.push(Position::Symbol);
+            if symbol_spacing {
+                positions.push(Position::Space);
+            }
+            if !format.sign_before_symbol {
+                positions.push(Position::Sign);
+            }
+            positions.push(Position::Amount);
+        } else {
+            positions.push(Position::Sign);
+            positions.push(Position::Amount);
+            if symbol_spacing {
+                positions.push(Position::Space);
+            }
+            positions.push(Position::Symbol);
+        }
+        positions
+    }
+
+    /// Returns formatter params for a locale format, rounded to the currency's exponent.
+    fn locale_params(
+        &self,
+        locale: Locale,
+        format: &LocalFormat,
+        positions: Vec<Position>,
+    ) -> Params {
+        let currency = self.currency;
+        Params {
+            digit_separator: format.digit_separator,
+            exponent_separator: format.exponent_separator,
+            separator_pattern: format.digit_separator_pattern(),
+            positions,
+            rounding: Some(currency.exponent),
+            display_exponent: Some(currency.exponent),
+            symbol: Some(self.display_symbol(Some(locale))),
+            code: Some(currency.iso_alpha_code),
+            locale: Some(locale),
+            force_sign: false,
+            zero_display: None,
+        }
+    }
+
+    /// Returns the amount in major and minor unit names (e.g. "3 dollars and 50 cents").
+    ///
+    /// The amount is rounded to the currency's exponent, and the minor units are omitted
+    /// when there are none.
+    pub fn to_words_simple(&self) -> String {
+        let exponent = self.currency.exponent;
+        let amount = self.rounded_amount().abs();
+        let major = amount.trunc();
+        let minor = (amount - major) * Decimal::new(10_i64.pow(exponent), 0);
+
+        let mut words = String::new();
+        if self.is_negative() {
+            words.push_str("negative ");
+        }
+        words.push_str(&format!(
+            "{} {}",
+            major,
+            pluralize(self.currency.major_unit_name, &major)
+        ));
+        if !minor.is_zero() {
+            let minor = minor.normalize();
+            words.push_str(&format!(
+                " and {} {}",
+                minor,
+                pluralize(self.currency.minor_unit_name, &minor)
+            ));
+        }
+        words
+    }
+
+    /// Returns the amount written out for a cheque (e.g. "One Thousand Two Hundred Thirty-Four
+    /// and 56/100 Dollars").
+    ///
+    /// The amount is rounded to the currency's exponent, and the minor units are written as a
+    /// fraction of a major unit. Currencies without minor units, like JPY, omit the fraction.
+    pub fn to_words(&self) -> String {
+        let exponent = self.currency.exponent;
+        let rounded = self.rounded_amount();
+        let amount = rounded.abs();
+        let major = amount.trunc();
+        let minor = (amount - major) * Decimal::new(10_i64.pow(exponent), 0);
+
+        let mut words = String::new();
+        if rounded.is_sign_negative() && !rounded.is_zero() {
+            words.push_str("Negative ");
+        }
+        words.push_str(&integer_to_words(major.to_u128().unwrap_or_default()));
+        if exponent > 0 {
+            words.push_str(&format!(
+                " and {:0width$}/{}",
+                minor.trunc(),
+                10_u64.pow(exponent),
+                width = exponent as usize
+            ));
+        }
+        words.push(' ');
+        words.push_str(&capitalize(&pluralize(
+            self.currency.major_unit_name,
+            &major,
+        )));
+        words
+    }
+}
+
+/// An iterator over Money from a start to an end amount (inclusive), created by `Money::range`.
+#[derive(Debug, Clone)]
+pub struct MoneyRange {
+    next: Option<Money>,
+    end: Decimal,
+    step: Decimal,
+}
+
+impl Iterator for MoneyRange {
+    type Item = Money;
+
+    fn next(&mut self) -> Option<Money> {
+        let current = self.next.take()?;
+        if current.amount > self.end {
+            return None;
+        }
+        self.next = current
+            .amount
+            .checked_add(self.step)
+            .map(|amount| Money::from_decimal(amount, current.currency));
+        Some(current)
+    }
+}
+
+/// Creates Money from an amount and a currency set one at a time, created by `Money::builder`.
+///
+/// `build` parses them like `Money::from_str`, and raises an InvalidAmount or InvalidCurrency
+/// error if either is missing.
+#[derive(Debug, Default, Clone)]
+pub struct MoneyBuilder {
+    amount: Option<String>,
+    currency: Option<String>,
+}
+
+impl MoneyBuilder {
+    /// Sets the amount, parsed in the currency's default locale (e.g. "1234.56").
+    pub fn amount(mut self, amount: &str) -> MoneyBuilder {
+        self.amount = Some(amount.to_string());
+        self
+    }
+
+    /// Sets the currency, as an ISO-4217 alphabetic or numeric code (e.g. "USD").
+    pub fn currency(mut self, currency: &str) -> MoneyBuilder {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    /// Returns the Money, or an error if the amount or currency is missing or invalid.
+    pub fn build(self) -> Result<Money, MoneyError> {
+        let currency = self.currency.ok_or(MoneyError::InvalidCurrency)?;
+        let amount = self.amount.ok_or(MoneyError::InvalidAmount)?;
+        Money::from_string(amount, currency)
+    }
+}
+
+/// The change of a Money from a baseline, created by `Money::compare_to`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PriceChange {
+    /// The signed difference from the baseline.
+    pub delta: Money,
+    /// The signed difference as a percentage of the baseline (e.g. 2.5 for +2.5%).
+    pub percent: Decimal,
+}
+
+/// Money which is never negative, for values like prices and quantities.
+///
+/// Creating one from a negative Money raises a NegativeAmount error, as does any arithmetic
+/// which would make it negative. Zero is allowed. It derefs to Money for reading.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PositiveMoney(Money);
+
+impl PositiveMoney {
+    /// Creates a PositiveMoney, raising a NegativeAmount error if the Money is negative.
+    pub fn new(money: Money) -> Result<PositiveMoney, MoneyError> {
+        if money.is_negative() {
+            return Err(MoneyError::NegativeAmount);
+        }
+        Ok(PositiveMoney(money))
+    }
+
+    /// Returns the wrapped Money.
+    pub fn into_inner(self) -> Money {
+        self.0
+    }
+
+    /// Adds another PositiveMoney of the same currency, without panicking on overflow.
+    pub fn checked_add(&self, other: &PositiveMoney) -> Result<PositiveMoney, MoneyError> {
+        PositiveMoney::new(self.0.checked_add(&other.0)?)
+    }
+
+    /// Subtracts another PositiveMoney of the same currency, raising a NegativeAmount error if
+    /// the result would be negative.
+    pub fn checked_sub(&self, other: &PositiveMoney) -> Result<PositiveMoney, MoneyError> {
+        PositiveMoney::new(self.0.checked_sub(&other.0)?)
+    }
+
+    /// Multiplies by a Decimal, raising a NegativeAmount error if the result would be negative.
+    pub fn checked_mul(&self, factor: Decimal) -> Result<PositiveMoney, MoneyError> {
+        PositiveMoney::new(self.0.checked_mul(factor)?)
+    }
+}
+
+impl Deref for PositiveMoney {
+    type Target = Money;
+
+    fn deref(&self) -> &Money {
+        &self.0
+    }
+}
+
+impl TryFrom<Money> for PositiveMoney {
+    type Error = MoneyError;
+
+    fn try_from(money: Money) -> Result<PositiveMoney, MoneyError> {
+        PositiveMoney::new(money)
+    }
+}
+
+impl From<PositiveMoney> for Money {
+    fn from(money: PositiveMoney) -> Money {
+        money.0
+    }
+}
+
+/// Converts an integer of minor units into a decimal of major units of a currency.
+///
+/// This is the scaling `Money::from_minor` uses (e.g. 1234 -> 12.34 in USD), for code that only
+/// stores a currency and an integer. Use `try_minor_to_major` to get an error instead of a panic.
+///
+/// # Panics
+///
+/// Panics if the amount is too large to fit in a Decimal (e.g. `i128::MAX`).
+pub fn minor_to_major(minor: i128, currency: &Currency) -> Decimal {
+    Decimal::from_i128_with_scale(minor, currency.exponent)
+}
+
+/// Converts an integer of minor units into a decimal of major units of a currency, returning an
+/// Overflow error if the amount is too large to fit in a Decimal.
+pub fn try_minor_to_major(minor: i128, currency: &Currency) -> Result<Decimal, MoneyError> {
+    Decimal::try_from_i128_with_scale(minor, currency.exponent).map_err(|_| MoneyError::Overflow)
+}
+
+/// Converts a decimal of major units into an integer of minor units of a currency.
+///
+/// The amount must be representable exactly in minor units (e.g. 12.34 -> 1234 in USD), otherwise
+/// a PrecisionExceeded error is returned rather than rounding it silently.
+pub fn major_to_minor(major: Decimal, currency: &Currency) -> Result<i128, MoneyError> {
+    let minor = major
+        .checked_mul(Decimal::from(10_i64.pow(currency.exponent)))
+        .ok_or(MoneyError::Overflow)?;
+    if !minor.fract().is_zero() {
+        return Err(MoneyError::PrecisionExceeded);
+    }
+    minor.to_i128().ok_or(MoneyError::Overflow)
+}
+
+/// Returns the str without a currency marker at its start or end, if it has one there.
+///
+/// Markers are matched case-insensitively, so that codes like "usd" are recognized.
+fn strip_marker<'a>(s: &'a str, marker: &str) -> Option<&'a str> {
+    let length = marker.len();
+    if s.get(..length)
+        .is_some_and(|x| x.eq_ignore_ascii_case(marker))
+    {
+        return Some(&s[length..]);
+    }
+    let start = s.len().checked_sub(length)?;
+    if s.get(start..)
+        .is_some_and(|x| x.eq_ignore_ascii_case(marker))
+    {
+        return Some(&s[..start]);
+    }
+    None
+}
+
+/// Returns the name of a unit, pluralized for the given count (e.g. "cent" -> "cents").
+///
+/// Names which are already plural, or which are the same in the plural like "yen", are
+/// returned unchanged, and irregular names like "real" -> "reais" are handled.
+fn pluralize(name: &str, count: &Decimal) -> String {
+    const INVARIANT: [&str; 2] = ["yen", "sen"];
+    const IRREGULAR: [(&str, &str); 3] =
+        [("paisa", "paise"), ("penny", "pence"), ("real", "reais")];
+    if *count == dec!(1) || name.ends_with('s') || INVARIANT.contains(&name) {
+        name.to_string()
+    } else if let Some((_, plural)) = IRREGULAR.iter().find(|(singular, _)| *singular == name) {
+        plural.to_string()
+    } else if let Some(stem) = name.strip_suffix('y') {
+        format!("{}ies", stem)
+    } else {
+        format!("{}s", name)
+    }
+}
+
+/// Returns an integer written out in title case English words (e.g. "Thirty-Four").
+fn integer_to_words(number: u128) -> String {
+    const ONES: [&str; 20] = [
+        "Zero",
+        "One",
+        "Two",
+        "Three",
+        "Four",
+        "Five",
+        "Six",
+        "Seven",
+        "Eight",
+        "Nine",
+        "Ten",
+        "Eleven",
+        "Twelve",
+        "Thirteen",
+        "Fourteen",
+        "Fifteen",
+        "Sixteen",
+        "Seventeen",
+        "Eighteen",
+        "Nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "Twenty", "Thirty", "Forty", "Fifty", "Sixty", "Seventy", "Eighty", "Ninety",
+    ];
+    const SCALES: [&str; 10] = [
+        "",
+        "Thousand",
+        "Million",
+        "Billion",
+        "Trillion",
+        "Quadrillion",
+        "Quintillion",
+        "Sextillion",
+        "Septillion",
+        "Octillion",
+    ];
+
+    if number == 0 {
+        return ONES[0].to_string();
+    }
+
+    let mut groups = Vec::new();
+    let mut remaining = number;
+    let mut scale = 0;
+    while remaining > 0 {
+        let group = (remaining % 1000) as usize;
+        if group > 0 {
+            let mut words = Vec::new();
+            if group >= 100 {
+                words.push(format!("{} Hundred", ONES[group / 100]));
+            }
+            match group % 100 {
+                0 => {}
+                rest if rest < 20 => words.push(ONES[rest].to_string()),
+                rest if rest % 10 == 0 => words.push(TENS[rest / 10].to_string()),
+                rest => words.push(format!("{}-{}", TENS[rest / 10], ONES[rest % 10])),
+            }
+            if scale > 0 {
+                words.push(SCALES[scale].to_string());
+            }
+            groups.push(words.join(" "));
+        }
+        remaining /= 1000;
+        scale += 1;
+    }
+    groups.reverse();
+    groups.join(" ")
+}
+
+/// Returns a str with its first character in upper case.
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Escapes the characters of a str that are special in HTML text and attributes.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Displays Money in its currency's default locale, or in the global locale if one is set.
+///
+/// The amount is rounded to the currency's exponent for presentation only (e.g. -2000.009 USD
+/// displays as -$2,000.01), and the stored amount is never changed. Use `round()` to change it.
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let locale = global_locale().unwrap_or(self.currency.default_locale);
+        self.write_to(f, locale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Iso::*;
+
+    #[test]
+    fn money_major_minor() {
+        let major_usd = Money::from_major(10, Currency::get(USD));
+        let minor_usd = Money::from_minor(1000, Currency::get(USD));
+        let new_usd = Money::new(1000, Currency::get(USD));
+        assert_eq!(major_usd, minor_usd);
+        assert_eq!(major_usd, new_usd);
+    }
+
+    #[test]
+    fn minor_and_major_units_round_trip() {
+        let usd = Currency::get(Iso::USD);
+        let jpy = Currency::get(Iso::JPY);
+        let bhd = Currency::get(Iso::BHD);
+
+        assert_eq!(minor_to_major(1234, usd), dec!(12.34));
+        assert_eq!(minor_to_major(-5, usd), dec!(-0.05));
+        assert_eq!(minor_to_major(1234, jpy), dec!(1234));
+        assert_eq!(minor_to_major(1234, bhd), dec!(1.234));
+
+        assert_eq!(major_to_minor(dec!(12.34), usd).unwrap(), 1234);
+        assert_eq!(major_to_minor(dec!(12.3), usd).unwrap(), 1230);
+        assert_eq!(major_to_minor(dec!(-0.05), usd).unwrap(), -5);
+        assert_eq!(major_to_minor(dec!(1234), jpy).unwrap(), 1234);
+        assert_eq!(
+            major_to_minor(minor_to_major(987_654_321, bhd), bhd).unwrap(),
+            987_654_321
+        );
+
+        assert_eq!(
+            major_to_minor(dec!(12.345), usd).unwrap_err(),
+            MoneyError::PrecisionExceeded
+        );
+        assert_eq!(
+            major_to_minor(dec!(0.5), jpy).unwrap_err(),
+            MoneyError::PrecisionExceeded
+        );
+        assert_eq!(
+            major_to_minor(Decimal::MAX, usd).unwrap_err(),
+            MoneyError::Overflow
+        );
+
+        assert_eq!(try_minor_to_major(1234, usd).unwrap(), dec!(12.34));
+        assert_eq!(
+            try_minor_to_major(i128::MAX, usd).unwrap_err(),
+            MoneyError::Overflow
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn minor_to_major_panics_on_overflow() {
+        minor_to_major(i128::MAX, Currency::get(Iso::USD));
+    }
+
+    #[test]
+    fn money_macro_accepts_minor_units() {
+        assert_eq!(money!(minor 1234, "USD"), money!("12.34", "USD"));
+        assert_eq!(money!(1234, "USD"), money!("1234.00", "USD"));
+        assert_eq!(money!(minor 5, "BHD"), money!("0.005", "BHD"));
+        assert_eq!(money!(minor 1234, "JPY"), money!(1234, "JPY"));
+        assert_eq!(format!("{}", money!(minor 1234, "USD")), "$12.34");
+    }
+
+    #[test]
+    fn money_macro_accepts_strict_literals() {
+        assert_eq!(money!(strict 1234, "USD"), money!(1234, "USD"));
+        assert_eq!(money!(strict "12.34", "USD"), money!("12.34", "USD"));
+        assert_eq!(money!(strict minor 1234, "USD"), money!("12.34", "USD"));
+    }
+
+    #[test]
+    fn try_money_returns_errors() {
+        let amount = "12.34";
+        assert_eq!(try_money!(amount, "USD").unwrap(), money!("12.34", "USD"));
+        assert_eq!(
+            try_money!(minor 1234, "USD").unwrap(),
+            money!("12.34", "USD")
+        );
+        assert_eq!(
+            try_money!("12a4", "USD").unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            try_money!(amount, "FAKE").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            try_money!(minor 1234, "FAKE").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn money_major_minor_accept_any_integer_type() {
+        let usd = Currency::get(USD);
+
+        let expected_money = money!("12.34", "USD");
+        assert_eq!(Money::from_minor(1234u32, usd), expected_money);
+        assert_eq!(Money::from_minor(1234i64, usd), expected_money);
+        assert_eq!(Money::from_minor(1234usize, usd), expected_money);
+        assert_eq!(Money::new(1234u16, usd), expected_money);
+
+        let expected_money = money!(-12, "USD");
+        assert_eq!(Money::from_major(-12i8, usd), expected_money);
+        assert_eq!(Money::from_major(-12isize, usd), expected_money);
+        assert_eq!(Money::from_major(12u64, usd), -1 * expected_money);
+    }
+
+    #[test]
+    fn money_from_minor_and_major_str() {
+        assert_eq!(
+            Money::from_minor_str("1234", "USD").unwrap(),
+            money!("12.34", "USD")
+        );
+        assert_eq!(
+            Money::from_minor_str("-1234", "USD").unwrap(),
+            money!("-12.34", "USD")
+        );
+        assert_eq!(
+            Money::from_minor_str("+1234", "BHD").unwrap(),
+            money!("1.234", "BHD")
+        );
+        assert_eq!(
+            Money::from_minor_str("1234", "JPY").unwrap(),
+            money!(1234, "JPY")
+        );
+        assert_eq!(
+            Money::from_minor_str("123456789012345678901234", "USD").unwrap(),
+            Money::from_major_str("1234567890123456789012.34", "USD").unwrap()
+        );
+
+        assert_eq!(
+            Money::from_major_str("12.34", "USD").unwrap(),
+            money!("12.34", "USD")
+        );
+        assert_eq!(
+            Money::from_major_str("-1234", "EUR").unwrap(),
+            money!(-1234, "EUR")
+        );
+
+        // Minor units must be whole numbers
+        for amount in &["12.34", "12,34", "", "-", "--1", "1e3", " 1"] {
+            assert_eq!(
+                Money::from_minor_str(amount, "USD").unwrap_err(),
+                MoneyError::InvalidAmount
+            );
+        }
+
+        // Major units must be plain decimals
+        assert_eq!(
+            Money::from_major_str("1,234.56", "USD").unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+
+        // Error if the currency is unknown
+        assert_eq!(
+            Money::from_minor_str("1234", "FAKE").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            Money::from_major_str("12.34", "FAKE").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn money_one_minor_unit() {
+        assert_eq!(
+            Money::one_minor_unit(Currency::get(USD)),
+            money!("0.01", "USD")
+        );
+        assert_eq!(Money::one_minor_unit(Currency::get(JPY)), money!(1, "JPY"));
+        assert_eq!(
+            Money::one_minor_unit(Currency::get(BHD)),
+            money!("0.001", "BHD")
+        );
+    }
+
+    #[test]
+    fn money_with_amount_keeps_currency() {
+        let money = money!("12.34", "GBP");
+        let doubled = money.with_amount(money.amount() * dec!(2));
+        assert_eq!(doubled, money!("24.68", "GBP"));
+        assert_eq!(money, money!("12.34", "GBP"));
+    }
+
+    #[test]
+    fn money_with_currency_relabels_without_converting() {
+        let usd = money!("12.34", "USD");
+        let eur = usd.with_currency(Currency::get(EUR));
+        assert_eq!(eur, money!("12,34", "EUR"));
+        assert_eq!(usd, money!("12.34", "USD"));
+    }
+
+    #[test]
+    fn money_from_and_into_tuples() {
+        let usd = Currency::get(USD);
+        let money = Money::from((dec!(12.34), usd));
+        assert_eq!(money, money!("12.34", "USD"));
+
+        let (amount, currency): (Decimal, &'static Currency) = money.into();
+        assert_eq!(amount, dec!(12.34));
+        assert_eq!(currency, usd);
+    }
+
+    #[test]
+    fn money_from_string_parses_correctly() {
+        let expected_money = Money::new(2999, Currency::get(GBP));
+        let money = Money::from_string("29.99".to_string(), "GBP".to_string()).unwrap();
+        assert_eq!(money, expected_money);
+    }
+
+    #[test]
+    fn money_explain_parse_error() {
+        assert_eq!(Money::explain_parse_error("1,234.56", "USD"), "");
+        assert_eq!(
+            Money::explain_parse_error("1.00", "XYZ"),
+            "unknown currency 'XYZ'"
+        );
+        assert_eq!(Money::explain_parse_error("", "USD"), "no amount");
+        assert_eq!(
+            Money::explain_parse_error("1.2.3", "USD"),
+            "two decimal separators"
+        );
+        assert_eq!(
+            Money::explain_parse_error("1.2.3.4", "USD"),
+            "3 decimal separators"
+        );
+        assert_eq!(
+            Money::explain_parse_error("12a4", "USD"),
+            "non-numeric character 'a' at position 3"
+        );
+        assert_eq!(
+            Money::explain_parse_error("1-1", "USD"),
+            "sign '-' at position 2 instead of the start"
+        );
+        assert_eq!(
+            Money::explain_parse_error("1.-5", "USD"),
+            "sign '-' at position 3 instead of the start"
+        );
+        assert_eq!(
+            Money::from_str("1.-5", "USD").unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            Money::explain_parse_error("1.", "USD"),
+            "no digits after the decimal separator"
+        );
+        assert_eq!(
+            Money::explain_parse_error("-.5", "USD"),
+            "no digits before the decimal separator"
+        );
+        assert_eq!(
+            Money::explain_parse_error("1.000,5", "USD"),
+            "digit separator after the decimal separator"
+        );
+        assert_eq!(
+            Money::explain_parse_error("99999999999", "USD"),
+            "amount is too large"
+        );
+        assert_eq!(
+            Money::explain_parse_error("99,999,999,999.5", "USD"),
+            "amount is too large"
+        );
+        assert_eq!(
+            Money::explain_parse_error("1.12345678901", "USD"),
+            "too many digits after the decimal separator"
+        );
+
+        // Separators follow the currency's locale
+        assert_eq!(Money::explain_parse_error("1.234,56", "EUR"), "");
+        assert_eq!(
+            Money::explain_parse_error("1,2,3", "EUR"),
+            "two decimal separators"
+        );
+    }
+
+    #[test]
+    fn money_builder() {
+        let money = Money::builder()
+            .amount("1234.56")
+            .currency("USD")
+            .build()
+            .unwrap();
+        assert_eq!(money, money!("1234.56", "USD"));
+
+        let money = Money::builder()
+            .currency("EUR")
+            .amount("1.234,56")
+            .build()
+            .unwrap();
+        assert_eq!(money, money!("1.234,56", "EUR"));
+
+        assert_eq!(
+            Money::builder().amount("1").build().unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            Money::builder().currency("USD").build().unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            Money::builder()
+                .amount("1.2.3")
+                .currency("USD")
+                .build()
+                .unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            Money::builder()
+                .amount("1")
+                .currency("FAKE")
+                .build()
+                .unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn money_from_str_in_space_grouped_locales() {
+        let expected_money = money!("1234.56", "USD");
+        assert_eq!(
+            Money::from_str_in("1 234,56", "EUR", Locale::FrFr).unwrap(),
+            money!("1.234,56", "EUR")
+        );
+        assert_eq!(
+            Money::from_str_in("1\u{a0}234,56", "USD", Locale::FrFr).unwrap(),
+            expected_money
+        );
+        assert_eq!(
+            Money::from_str_in("1\u{202f}234,56", "USD", Locale::FrFr).unwrap(),
+            expected_money
+        );
+        assert_eq!(
+            Money::from_str_in("-1 000 000", "USD", Locale::FrFr).unwrap(),
+            money!(-1000000, "USD")
+        );
+
+        // Other locales keep their own separators
+        assert_eq!(
+            Money::from_str_in("1.234,56", "USD", Locale::DeDe).unwrap(),
+            expected_money
+        );
+        assert_eq!(
+            Money::from_str_in("1 234.56", "USD", Locale::EnUs).unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            Money::from_str_in("1 234,5 6", "USD", Locale::FrFr).unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            Money::from_str_in("1 234,56", "FAKE", Locale::FrFr).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn money_from_str_allowed() {
+        let allowed = [Currency::get(USD), Currency::get(EUR)];
+
+        let money = Money::from_str_allowed("12.34", "USD", &allowed).unwrap();
+        assert_eq!(money, money!("12.34", "USD"));
+        let money = Money::from_str_allowed("12,34", "978", &allowed).unwrap();
+        assert_eq!(money, money!("12,34", "EUR"));
+
+        assert_eq!(
+            Money::from_str_allowed("12.34", "GBP", &allowed).unwrap_err(),
+            MoneyError::CurrencyNotAllowed
+        );
+        assert_eq!(
+            Money::from_str_allowed("12.34", "USD", &[]).unwrap_err(),
+            MoneyError::CurrencyNotAllowed
+        );
+
+        // Parse errors take precedence
+        assert_eq!(
+            Money::from_str_allowed("12.34", "FAKE", &allowed).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            Money::from_str_allowed("1.2.3", "GBP", &allowed).unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+    }
+
+    #[test]
+    fn money_from_loose_str() {
+        let expected_money = money!("1234.56", "USD");
+        assert_eq!(
+            Money::from_loose_str("USD 1,234.56").unwrap(),
+            expected_money
+        );
+        assert_eq!(
+            Money::from_loose_str("1,234.56 USD").unwrap(),
+            expected_money
+        );
+        assert_eq!(
+            Money::from_loose_str("1,234.56usd").unwrap(),
+            expected_money
+        );
+        assert_eq!(Money::from_loose_str("$1,234.56").unwrap(), expected_money);
+        assert_eq!(
+            Money::from_loose_str(" $ 1,234.56 ").unwrap(),
+            expected_money
+        );
+
+        // Signs before or after the marker
+        let expected_money = money!("-1234.56", "USD");
+        assert_eq!(Money::from_loose_str("-$1,234.56").unwrap(), expected_money);
+        assert_eq!(Money::from_loose_str("$-1,234.56").unwrap(), expected_money);
+        assert_eq!(
+            Money::from_loose_str("-1,234.56 USD").unwrap(),
+            expected_money
+        );
+
+        // The currency's locale is applied
+        assert_eq!(
+            Money::from_loose_str("€1.234,56").unwrap(),
+            money!("1.234,56", "EUR")
+        );
+        assert_eq!(
+            Money::from_loose_str("1.234,56 EUR").unwrap(),
+            money!("1.234,56", "EUR")
+        );
+
+        // Errors if there is no currency or amount
+        assert_eq!(
+            Money::from_loose_str("1,234.56").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            Money::from_loose_str("").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            Money::from_loose_str("USD").unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            Money::from_loose_str("$1,2a4").unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+
+        // Malformed signs and decimals are errors
+        for s in &[
+            "$1.+5", "$1.-5", "$--1", "-$-1", "$1-", "$1..5", "$1.5.5", "$.", "- $",
+        ] {
+            assert!(Money::from_loose_str(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn money_from_str_with_default() {
+        let usd = Currency::get(USD);
+        assert_eq!(
+            Money::from_str_with_default("1,234.56", usd).unwrap(),
+            money!("1234.56", "USD")
+        );
+        assert_eq!(
+            Money::from_str_with_default(" -1,234.56 ", usd).unwrap(),
+            money!("-1234.56", "USD")
+        );
+
+        // A detected currency wins over the default
+        assert_eq!(
+            Money::from_str_with_default("€1.234,56", usd).unwrap(),
+            money!("1.234,56", "EUR")
+        );
+        assert_eq!(
+            Money::from_str_with_default("1,234.56 GBP", usd).unwrap(),
+            money!("1234.56", "GBP")
+        );
+
+        assert_eq!(
+            Money::from_str_with_default("1,2a4", usd).unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            Money::from_str_with_default("$1,2a4", usd).unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+    }
+
+    #[test]
+    fn money_from_loose_str_with_multi_char_symbols() {
+        assert_eq!(Money::from_loose_str("د.إ100").unwrap(), money!(100, "AED"));
+        assert_eq!(
+            Money::from_loose_str("100 د.إ").unwrap(),
+            money!(100, "AED")
+        );
+        assert_eq!(
+            Money::from_loose_str("-د.إ1,234.50").unwrap(),
+            money!("-1234.50", "AED")
+        );
+        assert_eq!(
+            Money::from_loose_str("ب.د1.234").unwrap(),
+            money!("1.234", "BHD")
+        );
+        assert_eq!(
+            Money::from_loose_str("Fr. 10.05").unwrap(),
+            money!("10.05", "CHF")
+        );
+
+        // Part of a symbol does not match
+        assert_eq!(
+            Money::from_loose_str("د.100").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn money_from_str_precision_modes() {
+        // Lenient parsing stores the full precision
+        let money = Money::from_str("1.009", "USD").unwrap();
+        assert_eq!(*money.amount(), dec!(1.009));
+
+        // Strict parsing rejects it
+        assert_eq!(
+            Money::from_str_strict("1.009", "USD").unwrap_err(),
+            MoneyError::PrecisionExceeded
+        );
+        assert_eq!(
+            Money::from_str_strict("1.01", "USD").unwrap(),
+            money!("1.01", "USD")
+        );
+        assert_eq!(
+            Money::from_str_strict("1.010", "USD").unwrap(),
+            money!("1.01", "USD")
+        );
+
+        // Truncated parsing drops it, toward zero
+        assert_eq!(
+            Money::from_str_truncated("1.009", "USD").unwrap(),
+            money!("1.00", "USD")
+        );
+        assert_eq!(
+            Money::from_str_truncated("-1.009", "USD").unwrap(),
+            money!("-1.00", "USD")
+        );
+        assert_eq!(
+            Money::from_str_truncated("1.0099", "BHD").unwrap(),
+            money!("1.009", "BHD")
+        );
+
+        // All modes still reject invalid input
+        assert_eq!(
+            Money::from_str_strict("1.0.0", "USD").unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            Money::from_str_truncated("1.0.0", "USD").unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+    }
+
+    #[test]
+    fn money_from_str_max_scale() {
+        assert_eq!(
+            Money::from_str_max_scale("1.005", "BHD", 2).unwrap_err(),
+            MoneyError::PrecisionExceeded
+        );
+        assert_eq!(
+            Money::from_str_max_scale("1.05", "BHD", 2).unwrap(),
+            money!("1.05", "BHD")
+        );
+        assert_eq!(
+            Money::from_str_max_scale("1.50", "USD", 1).unwrap(),
+            money!("1.5", "USD")
+        );
+        assert_eq!(
+            Money::from_str_max_scale("-1.55", "USD", 1).unwrap_err(),
+            MoneyError::PrecisionExceeded
+        );
+
+        // The cap can exceed the currency's exponent
+        assert_eq!(
+            Money::from_str_max_scale("1.0001", "USD", 4).unwrap(),
+            money!("1.0001", "USD")
+        );
+        assert_eq!(
+            Money::from_str_max_scale("1,000", "USD", 0).unwrap(),
+            money!(1000, "USD")
+        );
+        assert_eq!(
+            Money::from_str_max_scale("1.0.0", "USD", 2).unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+    }
+
+    #[test]
+    fn money_from_str_precision_modes_for_zero_exponent_currencies() {
+        // Whole amounts parse in every mode
+        assert_eq!(
+            Money::from_str("1,000", "JPY").unwrap(),
+            money!(1000, "JPY")
+        );
+        assert_eq!(
+            Money::from_str_strict("1,000", "JPY").unwrap(),
+            money!(1000, "JPY")
+        );
+        assert_eq!(
+            Money::from_str_strict("1000.0", "JPY").unwrap(),
+            money!(1000, "JPY")
+        );
+
+        // Strict parsing rejects fractional amounts
+        assert_eq!(
+            Money::from_str_strict("1000.5", "JPY").unwrap_err(),
+            MoneyError::PrecisionExceeded
+        );
+
+        // Lenient parsing stores them, and round() fixes them
+        let mut money = Money::from_str("1000.6", "JPY").unwrap();
+        assert_eq!(*money.amount(), dec!(1000.6));
+        assert!(money.needs_rounding());
+        money.round();
+        assert_eq!(money, money!(1001, "JPY"));
+        assert_eq!(format!("{}", money), "¥1,001");
+
+        // Truncated parsing drops them
+        assert_eq!(
+            Money::from_str_truncated("1000.9", "JPY").unwrap(),
+            money!(1000, "JPY")
+        );
+    }
+
+    #[test]
+    fn money_from_string_parses_signs() {
+        let expected_money = Money::new(-300, Currency::get(GBP));
+        let money = Money::from_string("-3".to_string(), "GBP".to_string()).unwrap();
+        assert_eq!(money, expected_money);
+
+        let expected_money = Money::new(300, Currency::get(GBP));
+        let money = Money::from_string("+3".to_string(), "GBP".to_string()).unwrap();
+        assert_eq!(money, expected_money);
+    }
+
+    #[test]
+    fn money_from_string_ignores_separators() {
+        let expected_money = Money::new(100000000, Currency::get(GBP));
+        let money = Money::from_string("1,000,000".to_string(), "GBP".to_string()).unwrap();
+        assert_eq!(money, expected_money);
+    }
+
+    #[test]
+    fn money_from_string_parse_errs() {
+        // If the delimiter preceeds the separators
+        let money = Money::from_string("1.0000,000".to_string(), "GBP".to_string());
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+
+        // If there are multiple delimiters
+        let money = Money::from_string("1.0000.000".to_string(), "GBP".to_string());
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+
+        // If there is an unrecognized character
+        let money = Money::from_string("1.0000!000".to_string(), "GBP".to_string());
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+
+        // If there are no characters other than separators
+        let exponent_separator_only = Money::from_string(",".to_string(), "GBP".to_string());
+        let amount_separator_only = Money::from_string(".".to_string(), "GBP".to_string());
+        let both_separators = Money::from_string(",,.".to_string(), "GBP".to_string());
+        assert_eq!(
+            exponent_separator_only.unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            amount_separator_only.unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(both_separators.unwrap_err(), MoneyError::InvalidAmount);
+    }
+
+    #[test]
+    fn money_range() {
+        let tiers: Vec<Money> =
+            Money::range(money!(10, "USD"), money!(12, "USD"), money!("0.5", "USD"))
+                .unwrap()
+                .collect();
+        let expected = vec![
+            money!("10", "USD"),
+            money!("10.5", "USD"),
+            money!("11", "USD"),
+            money!("11.5", "USD"),
+            money!("12", "USD"),
+        ];
+        assert_eq!(tiers, expected);
+
+        // The end is not exceeded when the step doesn't divide the range evenly
+        let tiers = Money::range(money!(1, "USD"), money!(2, "USD"), money!("0.3", "USD")).unwrap();
+        assert_eq!(tiers.last(), Some(money!("1.9", "USD")));
+
+        // The range is empty if start is greater than end
+        let mut tiers = Money::range(money!(2, "USD"), money!(1, "USD"), money!(1, "USD")).unwrap();
+        assert_eq!(tiers.next(), None);
+
+        // Error if the step is not positive
+        let tiers = Money::range(money!(1, "USD"), money!(2, "USD"), money!(0, "USD"));
+        assert_eq!(tiers.unwrap_err(), MoneyError::InvalidIncrement);
+        let tiers = Money::range(money!(1, "USD"), money!(2, "USD"), money!(-1, "USD"));
+        assert_eq!(tiers.unwrap_err(), MoneyError::InvalidIncrement);
+
+        // Error if the currencies don't match
+        let tiers = Money::range(money!(1, "USD"), money!(2, "GBP"), money!(1, "USD"));
+        assert_eq!(tiers.unwrap_err(), MoneyError::InvalidCurrency);
+        let tiers = Money::range(money!(1, "USD"), money!(2, "USD"), money!(1, "GBP"));
+        assert_eq!(tiers.unwrap_err(), MoneyError::InvalidCurrency);
+    }
+
+    #[test]
+    fn money_parse_batch() {
+        let rows = vec![
+            ("29.99", "GBP"),
+            ("1.000,50", "EUR"),
+            ("1.0000.000", "GBP"),
+            ("10", "FAKE"),
+        ];
+        let results = Money::parse_batch(&rows);
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0], Ok(money!("29.99", "GBP")));
+        assert_eq!(results[1], Ok(money!("1.000,50", "EUR")));
+        assert_eq!(results[2], Err(MoneyError::InvalidAmount));
+        assert_eq!(results[3], Err(MoneyError::InvalidCurrency));
+    }
+
+    #[test]
+    fn money_parse_amount_and_code() {
+        let expected_money = money!("12.34", "USD");
+        assert_eq!("12.34 USD".parse::<Money>().unwrap(), expected_money);
+        assert_eq!("USD 12.34".parse::<Money>().unwrap(), expected_money);
+        assert_eq!("12.34 840".parse::<Money>().unwrap(), expected_money);
+        assert_eq!("840 12.34".parse::<Money>().unwrap(), expected_money);
+        assert_eq!("840 124".parse::<Money>().unwrap(), money!(840, "CAD"));
+        assert_eq!(
+            " 1,000.00  USD ".parse::<Money>().unwrap(),
+            money!(1000, "USD")
+        );
+
+        // Errors if a part is missing, invalid, or unexpected
+        assert_eq!(
+            "12.34".parse::<Money>().unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            "12.34 USD EUR".parse::<Money>().unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            "USD EUR".parse::<Money>().unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            "12.34 FAKE".parse::<Money>().unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            "1.-5 USD".parse::<Money>().unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            "USD 1.+5".parse::<Money>().unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+    }
+
+    #[test]
+    fn money_format_rounds_exponent() {
+        // // 19.999 rounds to 20 for USD
+        let money = money!("19.9999", "USD");
+        assert_eq!("$20.00", format!("{}", money));
+
+        // // 29.111 rounds to 29.11 for USD
+        let money = money!("29.111", "USD");
+        assert_eq!("$29.11", format!("{}", money));
+
+        // // 39.1155 rounds to 39.116 for BHD
+        let money = money!("39.1155", "BHD");
+        assert_eq!("ب.د39.116", format!("{}", money));
+    }
+
+    #[test]
+    fn money_addition_and_subtraction() {
+        // Addition
+        assert_eq!(money!(2, "USD"), money!(1, "USD") + money!(1, "USD"));
+        // Subtraction
+        assert_eq!(money!(0, "USD"), money!(1, "USD") - money!(1, "USD"));
+    }
+
+    #[test]
+    fn money_checked_arithmetic() {
+        let usd = Currency::get(USD);
+
+        assert_eq!(
+            money!(1, "USD").checked_add(&money!(1, "USD")),
+            Ok(money!(2, "USD"))
+        );
+        assert_eq!(
+            money!(1, "USD").checked_sub(&money!(2, "USD")),
+            Ok(money!(-1, "USD"))
+        );
+        assert_eq!(
+            money!(2, "USD").checked_mul(dec!(1.5)),
+            Ok(money!(3, "USD"))
+        );
+
+        // Error if the currencies don't match
+        assert_eq!(
+            money!(1, "USD").checked_add(&money!(1, "GBP")).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            money!(1, "USD").checked_sub(&money!(1, "GBP")).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+
+        // Error if the amount overflows
+        let max = Money::from_decimal(Decimal::MAX, usd);
+        let min = Money::from_decimal(Decimal::MIN, usd);
+        assert_eq!(max.checked_add(&max).unwrap_err(), MoneyError::Overflow);
+        assert_eq!(min.checked_sub(&max).unwrap_err(), MoneyError::Overflow);
+        assert_eq!(max.checked_mul(dec!(2)).unwrap_err(), MoneyError::Overflow);
+    }
+
+    #[test]
+    fn positive_money() {
+        let price = PositiveMoney::new(money!(10, "USD")).unwrap();
+        assert_eq!(*price, money!(10, "USD"));
+        assert!(price.is_positive());
+        assert!(PositiveMoney::new(money!(0, "USD")).is_ok());
+        assert_eq!(
+            PositiveMoney::new(money!("-0.01", "USD")).unwrap_err(),
+            MoneyError::NegativeAmount
+        );
+        assert_eq!(
+            PositiveMoney::try_from(money!(-1, "USD")).unwrap_err(),
+            MoneyError::NegativeAmount
+        );
+
+        // Arithmetic is validated
+        let discount = PositiveMoney::new(money!(4, "USD")).unwrap();
+        assert_eq!(
+            price.checked_sub(&discount).unwrap().into_inner(),
+            money!(6, "USD")
+        );
+        assert_eq!(
+            discount.checked_sub(&price).unwrap_err(),
+            MoneyError::NegativeAmount
+        );
+        assert_eq!(*price.checked_add(&discount).unwrap(), money!(14, "USD"));
+        assert_eq!(*price.checked_mul(dec!(0.5)).unwrap(), money!(5, "USD"));
+        assert_eq!(
+            price.checked_mul(dec!(-1)).unwrap_err(),
+            MoneyError::NegativeAmount
+        );
+
+        let other = PositiveMoney::new(money!(1, "GBP")).unwrap();
+        assert_eq!(
+            price.checked_add(&other).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(Money::from(price), money!(10, "USD"));
+    }
+
+    #[test]
+    fn money_compare_to() {
+        let change = money!("102.50", "USD")
+            .compare_to(&money!(100, "USD"))
+            .unwrap();
+        assert_eq!(change.delta, money!("2.50", "USD"));
+        assert_eq!(change.percent, dec!(2.5));
+
+        let change = money!(75, "USD").compare_to(&money!(100, "USD")).unwrap();
+        assert_eq!(change.delta, money!(-25, "USD"));
+        assert_eq!(change.percent, dec!(-25));
+
+        // Changes are relative to the size of the baseline
+        let change = money!(-5, "USD").compare_to(&money!(-10, "USD")).unwrap();
+        assert_eq!(change.percent, dec!(50));
+
+        let change = money!(1, "USD").compare_to(&money!(3, "USD")).unwrap();
+        assert_eq!(change.percent.round_dp(4), dec!(-66.6667));
+
+        assert_eq!(
+            money!(1, "USD").compare_to(&money!(0, "USD")).unwrap_err(),
+            MoneyError::DivisionByZero
+        );
+        assert_eq!(
+            money!(1, "USD").compare_to(&money!(1, "GBP")).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        let usd = Currency::get(USD);
+        assert_eq!(
+            Money::from_decimal(dec!(1_000_000_000), usd)
+                .compare_to(&Money::from_decimal(Decimal::new(1, 20), usd))
+                .unwrap_err(),
+            MoneyError::Overflow
+        );
+    }
+
+    #[test]
+    fn money_approx_eq_units() {
+        let money = money!("10.00", "USD");
+        assert!(money.approx_eq_units(&money!("10.01", "USD"), 1));
+        assert!(money.approx_eq_units(&money!("9.99", "USD"), 1));
+        assert!(money.approx_eq_units(&money!("10.005", "USD"), 1));
+        assert!(!money.approx_eq_units(&money!("10.011", "USD"), 1));
+        assert!(money.approx_eq_units(&money!("10.05", "USD"), 5));
+        assert!(money.approx_eq_units(&money!("10", "USD"), 0));
+        assert!(!money.approx_eq_units(&money!("10.001", "USD"), 0));
+
+        // Units follow the currency's exponent
+        assert!(money!(100, "JPY").approx_eq_units(&money!(101, "JPY"), 1));
+        assert!(!money!("1", "BHD").approx_eq_units(&money!("1.002", "BHD"), 1));
+
+        assert!(!money.approx_eq_units(&money!("10.00", "GBP"), 1));
+    }
+
+    #[test]
+    fn money_display_eq() {
+        assert!(money!("2.001", "USD").display_eq(&money!("2.004", "USD")));
+        assert!(money!("2.00", "USD").display_eq(&money!("2", "USD")));
+        assert!(!money!("2.004", "USD").display_eq(&money!("2.006", "USD")));
+        assert_eq!(
+            money!("2.001", "USD").display_eq(&money!("2.004", "USD")),
+            money!("2.001", "USD").to_string() == money!("2.004", "USD").to_string()
+        );
+
+        // Precision follows the currency's exponent
+        assert!(money!("100.4", "JPY").display_eq(&money!(100, "JPY")));
+        assert!(!money!("1.0004", "BHD").display_eq(&money!("1.0016", "BHD")));
+
+        assert!(!money!("2.00", "USD").display_eq(&money!("2.00", "CAD")));
+    }
+
+    #[test]
+    fn money_saturating_sub() {
+        let balance = money!(10, "USD");
+        assert_eq!(
+            balance.saturating_sub(&money!(4, "USD")),
+            Ok(money!(6, "USD"))
+        );
+        assert_eq!(
+            balance.saturating_sub(&money!(10, "USD")),
+            Ok(money!(0, "USD"))
+        );
+        assert_eq!(
+            balance.saturating_sub(&money!("10.01", "USD")),
+            Ok(money!(0, "USD"))
+        );
+        assert!(!balance
+            .saturating_sub(&money!(20, "USD"))
+            .unwrap()
+            .is_negative());
+        assert_eq!(
+            balance.saturating_sub(&money!(1, "GBP")).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn money_try_div() {
+        assert_eq!(money!(3, "USD").try_div(2), Ok(money!("1.5", "USD")));
+        assert_eq!(money!(-3, "USD").try_div(-2), Ok(money!("1.5", "USD")));
+        assert_eq!(money!(1, "USD").try_div(3), Ok(money!(1, "USD") / 3));
+        assert_eq!(
+            money!(1, "USD").try_div(0).unwrap_err(),
+            MoneyError::DivisionByZero
+        );
+    }
+
+    #[test]
+    fn money_difference() {
+        let budget = money!(100, "USD");
+        let actual = money!("120.50", "USD");
+
+        assert_eq!(budget.difference(&actual), Ok(money!("-20.50", "USD")));
+        assert_eq!(actual.difference(&budget), Ok(money!("20.50", "USD")));
+        assert_eq!(budget.abs_difference(&actual), Ok(money!("20.50", "USD")));
+        assert_eq!(actual.abs_difference(&budget), Ok(money!("20.50", "USD")));
+
+        // Error if the currencies don't match
+        assert_eq!(
+            budget.difference(&money!(1, "GBP")).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            budget.abs_difference(&money!(1, "GBP")).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn money_multiplication_and_division() {
+        // Multiplication
         assert_eq!(money!(2, "USD"), money!(1, "USD") * 2);
         assert_eq!(money!(2, "USD"), money!(-1, "USD") * -2);
         assert_eq!(money!(2, "USD"), -2 * money!(-1, "USD"));
 
-        // Division
-        assert_eq!(money!(2, "USD"), money!(4, "USD") / 2);
-        assert_eq!(money!(2, "USD"), money!(-4, "USD") / -2);
-        assert_eq!(money!("0.5", "USD"), -1 / money!(-2, "USD"));
-        assert_eq!(money!("2.0", "USD"), money!(-2, "USD") / -1);
+        // Division
+        assert_eq!(money!(2, "USD"), money!(4, "USD") / 2);
+        assert_eq!(money!(2, "USD"), money!(-4, "USD") / -2);
+        assert_eq!(money!("0.5", "USD"), -1 / money!(-2, "USD"));
+        assert_eq!(money!("2.0", "USD"), money!(-2, "USD") / -1);
+
+        //MulAssign
+        let mut money = money!(1, "USD");
+        money *= 2;
+        assert_eq!(money!(2, "USD"), money);
+
+        //DivAssign
+        let mut money = money!(1, "USD");
+        money /= -2;
+        assert_eq!(money!("-0.5", "USD"), money);
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn money_comparison() {
+        // Greater Than
+        assert_eq!(true, money!(2, "USD") > money!(1, "USD"));
+        // Less Than
+        assert_eq!(false, money!(2, "USD") < money!(1, "USD"));
+        // Equals
+        assert_eq!(true, money!(1, "USD") == money!(1, "USD"));
+        assert_eq!(false, money!(1, "USD") == money!(1, "GBP"));
+        // is positive
+        assert_eq!(true, money!(1, "USD").is_positive());
+        assert_eq!(false, money!(0, "USD").is_positive());
+        assert_eq!(false, money!(-1, "USD").is_positive());
+        // is zero
+        assert_eq!(true, money!(0, "USD").is_zero());
+        assert_eq!(false, money!(1, "USD").is_zero());
+        assert_eq!(false, money!(-1, "USD").is_zero());
+        // is negative
+        assert_eq!(true, money!(-1, "USD").is_negative());
+        assert_eq!(false, money!(1, "USD").is_negative());
+        assert_eq!(false, money!(0, "USD").is_negative());
+    }
+
+    #[test]
+    fn money_comparison_with_numbers() {
+        // Integers
+        assert!(money!(100, "USD") > 50);
+        assert!(money!(100, "USD") < 150);
+        assert!(money!(100, "USD") == 100);
+        assert!(money!("100.01", "USD") != 100);
+        assert!(money!(-1, "USD") < 0);
+
+        // Decimals
+        assert!(money!("100.5", "USD") > dec!(100.49));
+        assert!(money!("100.5", "USD") <= dec!(100.5));
+        assert!(money!("100.50", "USD") == dec!(100.5));
+    }
+
+    #[test]
+    fn money_is_whole() {
+        assert!(money!("5.00", "USD").is_whole());
+        assert!(money!(-5, "USD").is_whole());
+        assert!(money!(0, "USD").is_whole());
+        assert!(!money!("5.01", "USD").is_whole());
+        assert!(!money!("-0.5", "USD").is_whole());
+        assert!(!money!("5.001", "BHD").is_whole());
+        assert!(money!(5, "JPY").is_whole());
+    }
+
+    #[test]
+    fn money_needs_rounding() {
+        assert!(money!("2.001", "USD").needs_rounding());
+        assert!(!money!("2.00", "USD").needs_rounding());
+        assert!(!money!("2.000", "USD").needs_rounding());
+        assert!(!money!("2.001", "BHD").needs_rounding());
+        assert!(money!("2.0001", "BHD").needs_rounding());
+    }
+
+    #[test]
+    fn money_is_exact() {
+        assert!(money!("2.00", "USD").is_exact());
+        assert!(money!("2.010", "USD").is_exact());
+        assert!(!money!("2.001", "USD").is_exact());
+        assert!(money!(2000, "JPY").is_exact());
+        assert!(!money!("2000.5", "JPY").is_exact());
+    }
+
+    #[test]
+    #[should_panic]
+    fn money_ops_greater_than_panics_on_different_currencies() {
+        assert!(money!(1, "USD") < money!(1, "GBP"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn money_ops_less_than_panics_on_different_currencies() {
+        assert!(money!(1, "USD") < money!(1, "GBP"));
+    }
+
+    #[test]
+    fn money_allocate() {
+        let money = money!(11, "USD");
+        let allocs = money.allocate(vec![1, 1, 1]).unwrap();
+        let expected_results = vec![money!(4, "USD"), money!(4, "USD"), money!(3, "USD")];
+        assert_eq!(expected_results, allocs);
+
+        // Error if the ratio vector is empty
+        let monies = money!(1, "USD").allocate(Vec::new());
+        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+
+        // Error if any ratio is zero
+        let monies = money!(1, "USD").allocate(vec![1, 0]);
+        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+    }
+
+    #[test]
+    fn money_allocate_decimal() {
+        let money = money!(100, "USD");
+        let shares = money
+            .allocate_decimal(&[dec!(0.5), dec!(0.3), dec!(0.2)])
+            .unwrap();
+        assert_eq!(
+            shares,
+            vec![money!(50, "USD"), money!(30, "USD"), money!(20, "USD")]
+        );
+
+        // Leftover minor units go to the shares which lost the most
+        let money = money!("0.10", "USD");
+        let shares = money
+            .allocate_decimal(&[dec!(0.33), dec!(0.33), dec!(0.34)])
+            .unwrap();
+        assert_eq!(
+            shares,
+            vec![
+                money!("0.03", "USD"),
+                money!("0.03", "USD"),
+                money!("0.04", "USD")
+            ]
+        );
+
+        let money = money!(1, "USD");
+        let shares = money
+            .allocate_decimal(&[dec!(1), dec!(1), dec!(1)])
+            .unwrap();
+        assert_eq!(
+            shares,
+            vec![
+                money!("0.34", "USD"),
+                money!("0.33", "USD"),
+                money!("0.33", "USD")
+            ]
+        );
+
+        let money = money!("-1.005", "BHD");
+        let shares = money.allocate_decimal(&[dec!(0.25), dec!(0.75)]).unwrap();
+        assert_eq!(
+            shares,
+            vec![money!("-0.251", "BHD"), money!("-0.754", "BHD")]
+        );
+        let total = shares
+            .iter()
+            .fold(money!(0, "BHD"), |total, share| total + share.clone());
+        assert_eq!(total, money);
+
+        assert_eq!(
+            money!(1, "USD").allocate_decimal(&[]).unwrap_err(),
+            MoneyError::InvalidRatio
+        );
+        assert_eq!(
+            money!(1, "USD")
+                .allocate_decimal(&[dec!(0.5), dec!(0)])
+                .unwrap_err(),
+            MoneyError::InvalidRatio
+        );
+        assert_eq!(
+            money!(1, "USD")
+                .allocate_decimal(&[dec!(1.5), dec!(-0.5)])
+                .unwrap_err(),
+            MoneyError::InvalidRatio
+        );
+        assert_eq!(
+            money!("1.001", "USD")
+                .allocate_decimal(&[dec!(1)])
+                .unwrap_err(),
+            MoneyError::PrecisionExceeded
+        );
+
+        // Error if the amount overflows in minor units
+        let max = Money::from_decimal(Decimal::MAX, Currency::get(Iso::USD));
+        assert_eq!(
+            max.allocate_decimal(&[dec!(1), dec!(1)]).unwrap_err(),
+            MoneyError::Overflow
+        );
+        assert_eq!(
+            money!(1, "USD")
+                .allocate_decimal(&[Decimal::MAX, Decimal::MAX])
+                .unwrap_err(),
+            MoneyError::Overflow
+        );
+    }
+
+    #[test]
+    fn money_allocate_to() {
+        let money = money!(11, "USD");
+        let monies = money.allocate_to(3).unwrap();
+        let expected_results = vec![money!(4, "USD"), money!(4, "USD"), money!(3, "USD")];
+        assert_eq!(expected_results, monies);
+
+        let monies = money!(1, "USD").allocate_to(0);
+        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+    }
+
+    #[test]
+    fn money_simple_and_compound_interest() {
+        let principal = money!(1000, "USD");
+
+        assert_eq!(
+            principal.simple_interest(dec!(0.05), 3).unwrap(),
+            money!(150, "USD")
+        );
+        assert_eq!(
+            principal.simple_interest(dec!(0.05), 0).unwrap(),
+            money!(0, "USD")
+        );
+
+        // Compound interest is not rounded
+        let interest = principal.compound_interest(dec!(0.05), 3).unwrap();
+        assert_eq!(*interest.amount(), dec!(157.625));
+        assert!(interest.needs_rounding());
+        assert_eq!(
+            principal.compound_interest(dec!(0.05), 1).unwrap(),
+            principal.simple_interest(dec!(0.05), 1).unwrap()
+        );
+
+        let interest = principal.compound_interest(dec!(0.005), 360).unwrap();
+        assert_eq!(
+            interest.to_canonical_string(RoundingStrategy::HalfUp),
+            "5022.58"
+        );
+
+        assert_eq!(
+            principal.compound_interest(dec!(100), 100).unwrap_err(),
+            MoneyError::Overflow
+        );
+    }
+
+    #[test]
+    fn money_total_with_vat() {
+        let lines = vec![money!(100, "EUR"), money!("50,50", "EUR")];
+        let (net, vat) = Money::total_with_vat(&lines, dec!(0.19), true).unwrap();
+        assert_eq!(net, money!("150,50", "EUR"));
+        assert_eq!(vat, money!("28,60", "EUR"));
+        let (net, vat) = Money::total_with_vat(&lines, dec!(0.19), false).unwrap();
+        assert_eq!(net, money!("150,50", "EUR"));
+        assert_eq!(vat, money!("28,60", "EUR"));
+
+        // 19% of 0.13 is 0.0247, which rounds to 0.02 per line, but to 0.07 on the 0.39 total
+        let lines = vec![
+            money!("0,13", "EUR"),
+            money!("0,13", "EUR"),
+            money!("0,13", "EUR"),
+        ];
+        let (_, per_line) = Money::total_with_vat(&lines, dec!(0.19), true).unwrap();
+        let (_, on_total) = Money::total_with_vat(&lines, dec!(0.19), false).unwrap();
+        assert_eq!(per_line, money!("0,06", "EUR"));
+        assert_eq!(on_total, money!("0,07", "EUR"));
+
+        assert_eq!(
+            Money::total_with_vat(&[], dec!(0.19), true).unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        assert_eq!(
+            Money::total_with_vat(&[money!(1, "EUR"), money!(1, "USD")], dec!(0.19), true)
+                .unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            Money::total_with_vat(&[money!(1, "EUR")], dec!(-0.19), true).unwrap_err(),
+            MoneyError::InvalidRatio
+        );
+    }
+
+    #[test]
+    fn money_div_rem() {
+        let (quotient, remainder) = money!(10, "USD").div_rem(3).unwrap();
+        assert_eq!(quotient, money!("3.33", "USD"));
+        assert_eq!(remainder, money!("0.01", "USD"));
+
+        let (quotient, remainder) = money!(-10, "USD").div_rem(3).unwrap();
+        assert_eq!(quotient, money!("-3.33", "USD"));
+        assert_eq!(remainder, money!("-0.01", "USD"));
+
+        let (quotient, remainder) = money!(10, "BHD").div_rem(3).unwrap();
+        assert_eq!(quotient, money!("3.333", "BHD"));
+        assert_eq!(remainder, money!("0.001", "BHD"));
+
+        // Error if dividing by zero
+        let result = money!(10, "USD").div_rem(0);
+        assert_eq!(result.unwrap_err(), MoneyError::DivisionByZero);
+
+        // Error if the amount overflows
+        let max = Money::from_decimal(Decimal::MAX, Currency::get(Iso::USD));
+        assert_eq!(max.div_rem(3).unwrap_err(), MoneyError::Overflow);
+    }
+
+    #[test]
+    fn money_denominations() {
+        let units = [
+            dec!(100),
+            dec!(50),
+            dec!(20),
+            dec!(10),
+            dec!(5),
+            dec!(1),
+            dec!(0.25),
+            dec!(0.10),
+            dec!(0.05),
+            dec!(0.01),
+        ];
+        assert_eq!(
+            money!("186.41", "USD").denominations(&units).unwrap(),
+            vec![
+                (dec!(100), 1),
+                (dec!(50), 1),
+                (dec!(20), 1),
+                (dec!(10), 1),
+                (dec!(5), 1),
+                (dec!(1), 1),
+                (dec!(0.25), 1),
+                (dec!(0.10), 1),
+                (dec!(0.05), 1),
+                (dec!(0.01), 1),
+            ]
+        );
+        assert_eq!(
+            money!("40.30", "USD").denominations(&units).unwrap(),
+            vec![(dec!(20), 2), (dec!(0.25), 1), (dec!(0.05), 1)]
+        );
+        assert!(money!(0, "USD").denominations(&units).unwrap().is_empty());
+
+        // Denominations are used largest first, whatever their order
+        assert_eq!(
+            money!(70, "USD")
+                .denominations(&[dec!(10), dec!(50)])
+                .unwrap(),
+            vec![(dec!(50), 1), (dec!(10), 2)]
+        );
+
+        assert_eq!(
+            money!("0.03", "USD")
+                .denominations(&[dec!(0.05)])
+                .unwrap_err(),
+            MoneyError::InvalidIncrement
+        );
+        assert_eq!(
+            money!(1, "USD").denominations(&[dec!(0)]).unwrap_err(),
+            MoneyError::InvalidIncrement
+        );
+        assert_eq!(
+            money!("1.001", "USD").denominations(&units).unwrap_err(),
+            MoneyError::PrecisionExceeded
+        );
+        assert_eq!(
+            money!(-1, "USD").denominations(&units).unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+        // Error if a count overflows
+        let max = Money::from_decimal(Decimal::MAX, Currency::get(Iso::USD));
+        assert_eq!(
+            max.denominations(&[dec!(0.01)]).unwrap_err(),
+            MoneyError::Overflow
+        );
+        let huge = Money::from_decimal(dec!(10_000_000_000), Currency::get(Iso::USD));
+        assert_eq!(
+            huge.denominations(&[dec!(1)]).unwrap_err(),
+            MoneyError::Overflow
+        );
+    }
+
+    #[test]
+    fn money_sort() {
+        let mut items = vec![
+            money!("2.50", "USD"),
+            money!("-1", "USD"),
+            money!("10", "USD"),
+            money!("2.5", "USD"),
+        ];
+        Money::sort(&mut items).unwrap();
+        assert_eq!(
+            items,
+            vec![
+                money!("-1", "USD"),
+                money!("2.50", "USD"),
+                money!("2.5", "USD"),
+                money!("10", "USD"),
+            ]
+        );
 
-        //MulAssign
-        let mut money = money!(1, "USD");
-        money *= 2;
-        assert_eq!(money!(2, "USD"), money);
+        let mut items: Vec<Money> = Vec::new();
+        assert!(Money::sort(&mut items).is_ok());
 
-        //DivAssign
-        let mut money = money!(1, "USD");
-        money /= -2;
-        assert_eq!(money!("-0.5", "USD"), money);
+        // Mixed currencies are left unsorted
+        let mut items = vec![money!(2, "USD"), money!(1, "EUR")];
+        assert_eq!(
+            Money::sort(&mut items).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(items, vec![money!(2, "USD"), money!(1, "EUR")]);
     }
 
     #[test]
-    fn money_comparison() {
-        // Greater Than
-        assert_eq!(true, money!(2, "USD") > money!(1, "USD"));
-        // Less Than
-        assert_eq!(false, money!(2, "USD") < money!(1, "USD"));
-        // Equals
-        assert_eq!(true, money!(1, "USD") == money!(1, "USD"));
-        assert_eq!(false, money!(1, "USD") == money!(1, "GBP"));
-        // is positive
-        assert_eq!(true, money!(1, "USD").is_positive());
-        assert_eq!(false, money!(0, "USD").is_positive());
-        assert_eq!(false, money!(-1, "USD").is_positive());
-        // is zero
-        assert_eq!(true, money!(0, "USD").is_zero());
-        assert_eq!(false, money!(1, "USD").is_zero());
-        assert_eq!(false, money!(-1, "USD").is_zero());
-        // is negative
-        assert_eq!(true, money!(-1, "USD").is_negative());
-        assert_eq!(false, money!(1, "USD").is_negative());
-        assert_eq!(false, money!(0, "USD").is_negative());
+    fn money_round_all_preserving_sum() {
+        use RoundingStrategy::*;
+
+        // The item which lost the most in rounding absorbs the difference
+        let items = vec![
+            money!("0.333", "USD"),
+            money!("0.333", "USD"),
+            money!("0.334", "USD"),
+        ];
+        let rounded = Money::round_all_preserving_sum(&items, HalfUp).unwrap();
+        let expected = vec![
+            money!("0.33", "USD"),
+            money!("0.33", "USD"),
+            money!("0.34", "USD"),
+        ];
+        assert_eq!(rounded, expected);
+
+        // Amounts can also be rounded down to meet the total
+        let items = vec![money!("0.335", "USD"), money!("0.335", "USD")];
+        let rounded = Money::round_all_preserving_sum(&items, HalfUp).unwrap();
+        assert_eq!(rounded, vec![money!("0.33", "USD"), money!("0.34", "USD")]);
+
+        // Exact amounts are left untouched
+        let items = vec![money!("1.10", "USD"), money!("2.20", "USD")];
+        let rounded = Money::round_all_preserving_sum(&items, HalfUp).unwrap();
+        assert_eq!(rounded, items);
+
+        // An empty list rounds to an empty list
+        assert!(Money::round_all_preserving_sum(&[], HalfUp)
+            .unwrap()
+            .is_empty());
+
+        // Error if the currencies are mixed
+        let items = vec![money!(1, "USD"), money!(1, "GBP")];
+        assert_eq!(
+            Money::round_all_preserving_sum(&items, HalfUp).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+
+        // Error if the sum overflows
+        let max = Money::from_decimal(Decimal::MAX, Currency::get(USD));
+        assert_eq!(
+            Money::round_all_preserving_sum(&[max.clone(), max], HalfUp).unwrap_err(),
+            MoneyError::Overflow
+        );
     }
 
     #[test]
-    #[should_panic]
-    fn money_ops_greater_than_panics_on_different_currencies() {
-        assert!(money!(1, "USD") < money!(1, "GBP"));
+    fn money_round_to_increment() {
+        use RoundingStrategy::*;
+
+        let rounded = |amount: &str, increment: Decimal, strategy: RoundingStrategy| {
+            let mut money = Money::from_str(amount, "CHF").unwrap();
+            money.round_to_increment(increment, strategy).unwrap();
+            money
+        };
+
+        assert_eq!(rounded("2.03", dec!(0.05), HalfUp), money!("2.05", "CHF"));
+        assert_eq!(rounded("2.02", dec!(0.05), HalfUp), money!("2.00", "CHF"));
+        assert_eq!(rounded("2.025", dec!(0.05), HalfUp), money!("2.05", "CHF"));
+        assert_eq!(
+            rounded("2.025", dec!(0.05), HalfDown),
+            money!("2.00", "CHF")
+        );
+        assert_eq!(rounded("2.01", dec!(0.05), Ceiling), money!("2.05", "CHF"));
+        assert_eq!(rounded("17", dec!(10), HalfUp), money!("20", "CHF"));
+
+        // Increments larger than the amount
+        assert_eq!(rounded("0.03", dec!(0.05), HalfUp), money!("0.05", "CHF"));
+        assert_eq!(rounded("0.02", dec!(0.05), HalfUp), money!("0", "CHF"));
+        assert_eq!(rounded("0.04", dec!(0.05), Down), money!("0", "CHF"));
+
+        // Negative amounts
+        assert_eq!(rounded("-2.03", dec!(0.05), HalfUp), money!("-2.05", "CHF"));
+        assert_eq!(
+            rounded("-2.025", dec!(0.05), HalfUp),
+            money!("-2.05", "CHF")
+        );
+        assert_eq!(rounded("-2.01", dec!(0.05), Floor), money!("-2.05", "CHF"));
+        assert_eq!(
+            rounded("-2.04", dec!(0.05), Ceiling),
+            money!("-2.00", "CHF")
+        );
+
+        // Error if the increment is not positive
+        let mut money = money!("2.03", "CHF");
+        assert_eq!(
+            money.round_to_increment(dec!(0), HalfUp).unwrap_err(),
+            MoneyError::InvalidIncrement
+        );
+        assert_eq!(
+            money.round_to_increment(dec!(-0.05), HalfUp).unwrap_err(),
+            MoneyError::InvalidIncrement
+        );
+        assert_eq!(money, money!("2.03", "CHF"));
+        // Error if the amount overflows
+        let mut max = Money::from_decimal(Decimal::MAX, Currency::get(Iso::CHF));
+        assert_eq!(
+            max.round_to_increment(dec!(0.05), HalfUp).unwrap_err(),
+            MoneyError::Overflow
+        );
+        assert_eq!(*max.amount(), Decimal::MAX);
     }
 
     #[test]
-    #[should_panic]
-    fn money_ops_less_than_panics_on_different_currencies() {
-        assert!(money!(1, "USD") < money!(1, "GBP"));
+    fn money_round_cash() {
+        let mut chf = money!("2.03", "CHF");
+        chf.round_cash();
+        assert_eq!(chf, money!("2.05", "CHF"));
+
+        let mut chf = money!("-2.02", "CHF");
+        chf.round_cash();
+        assert_eq!(chf, money!("-2.00", "CHF"));
+
+        // Currencies without cash rounding round to their exponent
+        let mut usd = money!("2.034", "USD");
+        usd.round_cash();
+        assert_eq!(usd, money!("2.03", "USD"));
     }
 
     #[test]
-    fn money_allocate() {
-        let money = money!(11, "USD");
-        let allocs = money.allocate(vec![1, 1, 1]).unwrap();
-        let expected_results = vec![money!(4, "USD"), money!(4, "USD"), money!(3, "USD")];
-        assert_eq!(expected_results, allocs);
+    fn money_to_words_simple() {
+        assert_eq!(
+            money!("3.50", "USD").to_words_simple(),
+            "3 dollars and 50 cents"
+        );
+        assert_eq!(
+            money!("1.01", "USD").to_words_simple(),
+            "1 dollar and 1 cent"
+        );
+        assert_eq!(money!(2, "USD").to_words_simple(), "2 dollars");
+        assert_eq!(
+            money!("0.05", "GBP").to_words_simple(),
+            "0 pounds and 5 pence"
+        );
+        assert_eq!(
+            money!("1.01", "GBP").to_words_simple(),
+            "1 pound and 1 penny"
+        );
+        assert_eq!(
+            money!("2.50", "INR").to_words_simple(),
+            "2 rupees and 50 paise"
+        );
+        assert_eq!(
+            money!("1.005", "BHD").to_words_simple(),
+            "1 dinar and 5 fils"
+        );
+        assert_eq!(
+            money!("-2,999", "EUR").to_words_simple(),
+            "negative 3 euros"
+        );
+        assert_eq!(money!(2, "JPY").to_words_simple(), "2 yen");
+    }
 
-        // Error if the ratio vector is empty
-        let monies = money!(1, "USD").allocate(Vec::new());
-        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+    #[test]
+    fn money_to_words() {
+        assert_eq!(
+            money!("1234.56", "USD").to_words(),
+            "One Thousand Two Hundred Thirty-Four and 56/100 Dollars"
+        );
+        assert_eq!(money!("1.01", "USD").to_words(), "One and 01/100 Dollar");
+        assert_eq!(money!(0, "USD").to_words(), "Zero and 00/100 Dollars");
+        assert_eq!(
+            money!("-20.5", "USD").to_words(),
+            "Negative Twenty and 50/100 Dollars"
+        );
+        assert_eq!(
+            money!("7019011.999", "USD").to_words(),
+            "Seven Million Nineteen Thousand Twelve and 00/100 Dollars"
+        );
+        assert_eq!(
+            money!("100000000", "USD").to_words(),
+            "One Hundred Million and 00/100 Dollars"
+        );
 
-        // Error if any ratio is zero
-        let monies = money!(1, "USD").allocate(vec![1, 0]);
-        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+        // Negative amounts that round to zero are not negative
+        assert_eq!(
+            money!("-0.001", "USD").to_words(),
+            "Zero and 00/100 Dollars"
+        );
+
+        // The fraction follows the currency's exponent
+        assert_eq!(
+            money!("40.005", "BHD").to_words(),
+            "Forty and 005/1000 Dinars"
+        );
+        assert_eq!(money!(1000, "JPY").to_words(), "One Thousand Yen");
     }
 
     #[test]
-    fn money_allocate_to() {
-        let money = money!(11, "USD");
-        let monies = money.allocate_to(3).unwrap();
-        let expected_results = vec![money!(4, "USD"), money!(4, "USD"), money!(3, "USD")];
-        assert_eq!(expected_results, monies);
+    fn money_to_iso20022() {
+        assert_eq!(
+            money!("1234.5", "USD").to_iso20022().unwrap(),
+            ("1234.50".to_string(), "USD".to_string())
+        );
+        assert_eq!(
+            money!("1.000", "BHD").to_iso20022().unwrap(),
+            ("1.000".to_string(), "BHD".to_string())
+        );
+        assert_eq!(
+            money!(1000, "JPY").to_iso20022().unwrap(),
+            ("1000".to_string(), "JPY".to_string())
+        );
+        assert_eq!(
+            money!(0, "EUR").to_iso20022().unwrap(),
+            ("0.00".to_string(), "EUR".to_string())
+        );
 
-        let monies = money!(1, "USD").allocate_to(0);
-        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+        assert_eq!(
+            money!("-1", "USD").to_iso20022().unwrap_err(),
+            MoneyError::NegativeAmount
+        );
+        assert_eq!(
+            money!("1.001", "USD").to_iso20022().unwrap_err(),
+            MoneyError::PrecisionExceeded
+        );
+
+        // At most 18 digits can be represented
+        let usd = Currency::get(USD);
+        assert_eq!(
+            Money::from_decimal(dec!(1234567890123456.78), usd)
+                .to_iso20022()
+                .unwrap(),
+            ("1234567890123456.78".to_string(), "USD".to_string())
+        );
+        assert_eq!(
+            Money::from_decimal(dec!(12345678901234567.89), usd)
+                .to_iso20022()
+                .unwrap_err(),
+            MoneyError::Overflow
+        );
+    }
+
+    #[test]
+    fn money_to_canonical_string() {
+        use RoundingStrategy::*;
+
+        assert_eq!(
+            money!("1,234.56", "USD").to_canonical_string(HalfUp),
+            "1234.56"
+        );
+        assert_eq!(
+            money!("1.234,5", "EUR").to_canonical_string(HalfUp),
+            "1234.50"
+        );
+        assert_eq!(
+            money!("-1234", "USD").to_canonical_string(HalfUp),
+            "-1234.00"
+        );
+        assert_eq!(money!(1000, "JPY").to_canonical_string(HalfUp), "1000");
+        assert_eq!(money!("1.5", "BHD").to_canonical_string(HalfUp), "1.500");
+
+        // The amount is rounded with the strategy
+        assert_eq!(money!("2.345", "USD").to_canonical_string(HalfUp), "2.35");
+        assert_eq!(money!("2.345", "USD").to_canonical_string(HalfEven), "2.34");
+        assert_eq!(money!("2.349", "USD").to_canonical_string(Down), "2.34");
+        assert_eq!(money!("-0.001", "USD").to_canonical_string(HalfUp), "0.00");
+    }
+
+    #[test]
+    fn money_extract_and_add_tax() {
+        // Exact splits
+        let (net, tax) = money!(119, "USD").extract_tax(dec!(0.19)).unwrap();
+        assert_eq!(net, money!(100, "USD"));
+        assert_eq!(tax, money!(19, "USD"));
+
+        // Inexact splits give the remainder to the tax
+        let gross = money!(10, "USD");
+        let (net, tax) = gross.extract_tax(dec!(0.19)).unwrap();
+        assert_eq!(net, money!("8.40", "USD"));
+        assert_eq!(tax, money!("1.60", "USD"));
+        assert_eq!(net + tax, gross);
+
+        // A zero rate has no tax
+        let (net, tax) = money!(10, "USD").extract_tax(dec!(0)).unwrap();
+        assert_eq!(net, money!(10, "USD"));
+        assert!(tax.is_zero());
+
+        // Adding tax
+        assert_eq!(
+            money!(100, "USD").add_tax(dec!(0.19)).unwrap(),
+            money!(119, "USD")
+        );
+        assert_eq!(
+            money!("8.40", "USD").add_tax(dec!(0.19)).unwrap(),
+            money!("10.00", "USD")
+        );
+        assert_eq!(
+            money!("0.05", "USD").add_tax(dec!(0.1)).unwrap(),
+            money!("0.06", "USD")
+        );
+
+        // Error if the rate is negative
+        assert_eq!(
+            money!(10, "USD").extract_tax(dec!(-0.1)).unwrap_err(),
+            MoneyError::InvalidRatio
+        );
+        assert_eq!(
+            money!(10, "USD").add_tax(dec!(-0.1)).unwrap_err(),
+            MoneyError::InvalidRatio
+        );
+        // Error if the amount overflows
+        let max = Money::from_decimal(Decimal::MAX, Currency::get(Iso::USD));
+        assert_eq!(
+            max.extract_tax(dec!(0.19)).unwrap_err(),
+            MoneyError::Overflow
+        );
+        assert_eq!(max.add_tax(dec!(0.19)).unwrap_err(), MoneyError::Overflow);
+        assert_eq!(
+            money!(10, "USD").add_tax(Decimal::MAX).unwrap_err(),
+            MoneyError::Overflow
+        );
     }
 
     #[test]
@@ -528,6 +3555,23 @@ mod tests {
         assert_eq!(format!("{}", inr), expected_inr_fmt);
     }
 
+    #[test]
+    fn money_fmt_does_not_mutate_amount() {
+        let money = money!("-2000.009", "USD");
+        assert_eq!(format!("{}", money), "-$2,000.01");
+        assert_eq!(money.format_in(Locale::EnEu), "-$2.000,01");
+        assert_eq!(*money.amount(), dec!(-2000.009));
+    }
+
+    #[test]
+    fn money_fmt_pads_to_exponent() {
+        let usd = Money::from_major(10, Currency::get(USD));
+        assert_eq!(format!("{}", usd), "$10.00");
+
+        let bhd = Money::from_major(10, Currency::get(BHD));
+        assert_eq!(format!("{}", bhd), "ب.د10.000");
+    }
+
     #[test]
     fn money_fmt_places_symbols_correctly() {
         let money = money!(0, "USD");
@@ -546,6 +3590,257 @@ mod tests {
         assert_eq!(format!("{}", money), expected_fmt);
     }
 
+    #[test]
+    fn money_format_in_uses_locale_separators() {
+        let money = money!("-1000000.50", "USD");
+        assert_eq!(money.format_in(Locale::EnUs), "-$1,000,000.50");
+        assert_eq!(money.format_in(Locale::EnEu), "-$1.000.000,50");
+        assert_eq!(money.format_in(Locale::FrFr), "-1 000 000,50 $");
+        assert_eq!(
+            money!("1234,56", "EUR").format_in(Locale::FrFr),
+            "1 234,56 €"
+        );
+    }
+
+    #[test]
+    fn money_write_to() {
+        let mut buffer = String::from("Total: ");
+        money!("-2000.009", "USD")
+            .write_to(&mut buffer, Locale::EnUs)
+            .unwrap();
+        buffer.push_str(", ");
+        money!("2000,009", "EUR")
+            .write_to(&mut buffer, Locale::DeDe)
+            .unwrap();
+        assert_eq!(buffer, "Total: -$2,000.01, 2.000,01 €");
+    }
+
+    #[test]
+    fn money_write_to_a_writer_which_formats_money() {
+        // A writer which formats Money while it is written to
+        struct Annotating(String);
+
+        impl fmt::Write for Annotating {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.push_str(s);
+                if s.starts_with('$') {
+                    self.0.push_str(&format!(" (~{})", money!(1, "USD")));
+                }
+                Ok(())
+            }
+        }
+
+        let mut writer = Annotating(String::new());
+        money!(5, "USD")
+            .write_to(&mut writer, Locale::EnUs)
+            .unwrap();
+        assert_eq!(writer.0, "$ (~$1.00)5.00");
+    }
+
+    #[test]
+    fn money_format_spaces_symbols_by_currency() {
+        let money = money!("1.234,56", "BRL");
+        assert_eq!(format!("{}", money), "R$ 1.234,56");
+        assert_eq!(format!("{}", money!("-1.234,56", "BRL")), "-R$ 1.234,56");
+        assert_eq!(money.format_in(Locale::EnUs), "R$ 1,234.56");
+
+        // The locale's spacing wins over the currency's
+        assert_eq!(money.format_in(Locale::DeDe), "1.234,56 R$");
+        assert_eq!(money!(5, "USD").format_in(Locale::EnUs), "$5.00");
+
+        assert_eq!(
+            Money::from_loose_str("R$ 1.234,56").unwrap(),
+            money!("1.234,56", "BRL")
+        );
+        assert_eq!(money.to_words_simple(), "1234 reais and 56 centavos");
+    }
+
+    #[test]
+    fn money_format_in_reuses_params_per_currency_and_locale() {
+        let usd = money!("-1000.5", "USD");
+        let eur = money!("-1000,5", "EUR");
+        for _ in 0..2 {
+            assert_eq!(usd.format_in(Locale::EnUs), "-$1,000.50");
+            assert_eq!(usd.format_in(Locale::DeDe), "-1.000,50 $");
+            assert_eq!(eur.format_in(Locale::EnUs), "-€1,000.50");
+            assert_eq!(eur.format_in(Locale::DeDe), "-1.000,50 €");
+        }
+    }
+
+    #[test]
+    fn money_format_in_places_symbols_by_locale() {
+        let money = money!("2000,009", "EUR");
+        assert_eq!(money.format_in(Locale::EnEu), "€2.000,01");
+        assert_eq!(money.format_in(Locale::DeDe), "2.000,01 €");
+        let money = money!("-2000,009", "EUR");
+        assert_eq!(money.format_in(Locale::DeDe), "-2.000,01 €");
+    }
+
+    #[test]
+    fn money_format_in_places_signs_by_locale() {
+        // The sign precedes the symbol
+        assert_eq!(money!(-5, "EUR").format_in(Locale::EnEu), "-€5,00");
+
+        // The sign follows the symbol
+        assert_eq!(money!(-5, "EUR").format_in(Locale::NlNl), "€ -5,00");
+        assert_eq!(money!(5, "EUR").format_in(Locale::NlNl), "€ 5,00");
+
+        // The sign always precedes the amount when the symbol follows it
+        assert_eq!(money!(-5, "AED").format_in(Locale::NlNl), "-5,00 د.إ");
+    }
+
+    #[test]
+    fn money_symbol_first_for() {
+        assert!(money!(1, "USD").symbol_first_for(None));
+        assert!(!money!(1, "AED").symbol_first_for(None));
+        assert!(money!(1, "EUR").symbol_first_for(Some(Locale::EnEu)));
+        assert!(!money!(1, "EUR").symbol_first_for(Some(Locale::DeDe)));
+        assert!(!money!(1, "AED").symbol_first_for(Some(Locale::EnUs)));
+
+        // It matches the formatted placement
+        let money = money!(1, "EUR");
+        assert!(!money
+            .format_in(Locale::DeDe)
+            .starts_with(money.display_symbol(None)));
+    }
+
+    #[test]
+    fn money_display_symbol() {
+        let money = money!("-2000.009", "AED");
+        assert_eq!(money.display_symbol(None), "د.إ");
+        assert_eq!(money.display_symbol(Some(Locale::DeDe)), "د.إ");
+        assert!(money
+            .format_in(Locale::EnUs)
+            .contains(money.display_symbol(Some(Locale::EnUs))));
+        assert_eq!(money!(1, "CHF").display_symbol(None), "Fr.");
+
+        // Shared symbols are told apart in locales which would take them for another currency
+        let usd = money!(5, "USD");
+        let cad = money!(5, "CAD");
+        assert_eq!(usd.display_symbol(None), "$");
+        assert_eq!(usd.display_symbol(Some(Locale::EnCa)), "US$");
+        assert_eq!(usd.format_in(Locale::EnCa), "US$5.00");
+        assert_eq!(cad.display_symbol(None), "$");
+        assert_eq!(cad.to_string(), "$5.00");
+        assert_eq!(cad.display_symbol(Some(Locale::EnUs)), "CA$");
+        assert_eq!(cad.format_in(Locale::EnUs), "CA$5.00");
+        assert_eq!(cad.format_in(Locale::FrFr), "5,00 CA$");
+    }
+
+    #[test]
+    fn money_to_string_symbol_and_code() {
+        assert_eq!(
+            money!("2000.009", "USD").to_string_symbol_and_code(),
+            "$2,000.01 USD"
+        );
+        assert_eq!(
+            money!("-2000.009", "USD").to_string_symbol_and_code(),
+            "-$2,000.01 USD"
+        );
+        assert_eq!(
+            money!("-1000000.4", "JPY").to_string_symbol_and_code(),
+            "-¥1,000,000 JPY"
+        );
+        assert_eq!(money!(5, "AED").to_string_symbol_and_code(), "5.00د.إ AED");
+    }
+
+    #[test]
+    fn money_to_html() {
+        assert_eq!(
+            money!("2000.009", "USD").to_html("sym"),
+            "<span class=\"sym\">$</span><span class=\"amount\">2,000.01</span>"
+        );
+        assert_eq!(
+            money!("-2000.009", "USD").to_html("sym"),
+            "<span class=\"sign\">-</span><span class=\"sym\">$</span><span class=\"amount\">2,000.01</span>"
+        );
+        assert_eq!(
+            money!(5, "AED").to_html("sym"),
+            "<span class=\"amount\">5.00</span><span class=\"sym\">د.إ</span>"
+        );
+
+        // The symbol class is escaped
+        assert_eq!(
+            money!(5, "USD").to_html("a\" onclick=\"x"),
+            "<span class=\"a&quot; onclick=&quot;x\">$</span><span class=\"amount\">5.00</span>"
+        );
+    }
+
+    #[test]
+    fn money_to_f64_rounded() {
+        let money = money!("1234.5678", "USD");
+        assert_eq!(money.to_f64_rounded(2), 1234.57);
+        assert_eq!(money.to_f64_rounded(0), 1235.0);
+        assert_eq!(money!("-0.125", "USD").to_f64_rounded(2), -0.13);
+        assert_eq!(money!(1, "USD").to_f64_rounded(2), 1.0);
+        assert_eq!(money!("0.1", "USD").to_f64_rounded(5), 0.1);
+    }
+
+    #[test]
+    fn money_round_uses_the_currency_rounding_strategy() {
+        static HALF_EVEN_USD: Currency = Currency {
+            cash_rounding: None,
+            default_locale: Locale::EnUs,
+            exponent: 2,
+            international_symbol: Some("US$"),
+            iso_alpha_code: "USD",
+            iso_numeric_code: "840",
+            major_unit_name: "dollar",
+            minor_unit_name: "cent",
+            name: "United States Dollar",
+            rounding_strategy: Some(RoundingStrategy::HalfEven),
+            symbol: "$",
+            symbol_first: true,
+            symbol_spacing: false,
+        };
+
+        // Currencies without a strategy round half up
+        let mut money = money!("2.345", "USD");
+        money.round();
+        assert_eq!(money, money!("2.35", "USD"));
+        let mut money = money!("-2.345", "USD");
+        money.round();
+        assert_eq!(money, money!("-2.35", "USD"));
+
+        let mut money = Money::from_decimal(dec!(2.345), &HALF_EVEN_USD);
+        money.round();
+        assert_eq!(*money.amount(), dec!(2.34));
+        let mut money = Money::from_decimal(dec!(2.355), &HALF_EVEN_USD);
+        money.round();
+        assert_eq!(*money.amount(), dec!(2.36));
+
+        // Tax is rounded the same way
+        assert_eq!(
+            money!("0.25", "USD").add_tax(dec!(0.1)).unwrap(),
+            money!("0.28", "USD")
+        );
+        let money = Money::from_decimal(dec!(0.25), &HALF_EVEN_USD);
+        assert_eq!(*money.add_tax(dec!(0.1)).unwrap().amount(), dec!(0.27));
+    }
+
+    #[test]
+    fn money_display_rounds_like_round() {
+        for (amount, currency, expected) in &[
+            ("2.345", "USD", "$2.35"),
+            ("2.005", "USD", "$2.01"),
+            ("-2.345", "USD", "-$2.35"),
+            ("1000.5", "JPY", "¥1,001"),
+        ] {
+            let money = Money::from_str(amount, currency).unwrap();
+            let mut rounded = money.clone();
+            rounded.round();
+            assert_eq!(money.to_string(), *expected);
+            assert_eq!(money.to_string(), rounded.to_string());
+            assert!(money.display_eq(&rounded));
+        }
+        assert_eq!(money!("2.345", "USD").format_in(Locale::DeDe), "2,35 $");
+        assert_eq!(money!("2.005", "USD").to_words(), "Two and 01/100 Dollars");
+        assert_eq!(
+            money!("0.005", "USD").to_words_simple(),
+            "0 dollars and 1 cent"
+        );
+    }
+
     #[test]
     // Dividing 20 by 3 rounds to 6.67 in USD and 6.667 in BHD
     fn money_precision_and_rounding() {
@@ -0,0 +1,35 @@
+/// The `RoundingStrategy` type, which describes how an amount is rounded to a given precision.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RoundingStrategy {
+    /// Rounds to the nearest value, and halfway values away from zero (e.g. 2.5 -> 3, -2.5 -> -3).
+    HalfUp,
+    /// Rounds to the nearest value, and halfway values toward zero (e.g. 2.5 -> 2, -2.5 -> -2).
+    HalfDown,
+    /// Rounds to the nearest value, and halfway values to the even neighbour (e.g. 2.5 -> 2, 3.5 -> 4).
+    HalfEven,
+    /// Always rounds away from zero (e.g. 2.1 -> 3, -2.1 -> -3).
+    Up,
+    /// Always rounds toward zero (e.g. 2.9 -> 2, -2.9 -> -2).
+    Down,
+    /// Always rounds toward negative infinity (e.g. 2.9 -> 2, -2.1 -> -3).
+    Floor,
+    /// Always rounds toward positive infinity (e.g. 2.1 -> 3, -2.9 -> -2).
+    Ceiling,
+}
+
+impl RoundingStrategy {
+    /// Returns the equivalent strategy used by Decimal.
+    pub(crate) fn decimal_strategy(self) -> rust_decimal::RoundingStrategy {
+        use rust_decimal::RoundingStrategy::*;
+
+        match self {
+            RoundingStrategy::HalfUp => MidpointAwayFromZero,
+            RoundingStrategy::HalfDown => MidpointTowardZero,
+            RoundingStrategy::HalfEven => MidpointNearestEven,
+            RoundingStrategy::Up => AwayFromZero,
+            RoundingStrategy::Down => ToZero,
+            RoundingStrategy::Floor => ToNegativeInfinity,
+            RoundingStrategy::Ceiling => ToPositiveInfinity,
+        }
+    }
+}
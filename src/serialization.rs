@@ -0,0 +1,110 @@
+use crate::currency::Currency;
+use crate::money::Money;
+use crate::rounding::RoundingStrategy;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Serializes Money as its amount string and ISO alphabetic currency code.
+///
+/// The amount is written as a string (e.g. `{"amount": "12.34", "currency": "USD"}`) so that
+/// no precision is lost. Amounts are written in their canonical form, with the currency's
+/// number of decimals, unless they are more precise than that.
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let amount = if self.needs_rounding() {
+            self.amount().to_string()
+        } else {
+            self.to_canonical_string(RoundingStrategy::HalfEven)
+        };
+
+        let mut state = serializer.serialize_struct("Money", 2)?;
+        state.serialize_field("amount", &amount)?;
+        state.serialize_field("currency", self.currency().iso_alpha_code)?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawMoney {
+    amount: String,
+    currency: String,
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        let raw = RawMoney::deserialize(deserializer)?;
+        let currency = Currency::find(&raw.currency).map_err(de::Error::custom)?;
+        let amount = Decimal::from_str(&raw.amount).map_err(de::Error::custom)?;
+        Ok(Money::from_decimal(amount, currency))
+    }
+}
+
+/// A wrapper which serializes Money with its amount as a number instead of a string.
+///
+/// The amount is rounded to the currency's exponent and emitted as a floating point number
+/// (e.g. `{"amount": 12.34, "currency": "USD"}`), which is what some APIs expect. Unlike the
+/// default string form this is lossy: precision beyond the exponent is rounded away, the value
+/// passes through an f64, and trailing zeros are not preserved (12.30 is emitted as 12.3).
+#[derive(Debug, PartialEq, Clone)]
+pub struct MoneyAsNumber(pub Money);
+
+impl Serialize for MoneyAsNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let money = &self.0;
+        let amount = money.rounded_amount();
+        let amount = amount
+            .to_f64()
+            .ok_or_else(|| serde::ser::Error::custom("Amount cannot be represented as a number"))?;
+
+        let mut state = serializer.serialize_struct("Money", 2)?;
+        state.serialize_field("amount", &amount)?;
+        state.serialize_field("currency", money.currency().iso_alpha_code)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::money;
+
+    #[test]
+    fn serialize_money_as_string() {
+        let money = money!("12.345", "USD");
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":"12.345","currency":"USD"}"#);
+
+        let money = money!("12.3", "USD");
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":"12.30","currency":"USD"}"#);
+    }
+
+    #[test]
+    fn deserialize_money_from_string() {
+        let money: Money = serde_json::from_str(r#"{"amount":"12.345","currency":"USD"}"#).unwrap();
+        assert_eq!(money, money!("12.345", "USD"));
+
+        let result: Result<Money, _> = serde_json::from_str(r#"{"amount":"1","currency":"XYZ"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serialize_money_as_number() {
+        let money = MoneyAsNumber(money!("12.346", "USD"));
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":12.35,"currency":"USD"}"#);
+
+        let money = MoneyAsNumber(money!("39.1155", "BHD"));
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":39.116,"currency":"BHD"}"#);
+
+        // Halfway amounts round up, like Money::round
+        let money = MoneyAsNumber(money!("2.005", "USD"));
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":2.01,"currency":"USD"}"#);
+    }
+}
@@ -0,0 +1,21 @@
+// The global locale is shared across the process, so it is tested in its own binary to keep
+// it from leaking into the Display tests of other modules.
+use rusty_money::{clear_global_locale, global_locale, money, set_global_locale, Locale};
+
+#[test]
+fn global_locale_overrides_currency_default() {
+    let money = money!("-1000.50", "USD");
+    assert_eq!(global_locale(), None);
+    assert_eq!(format!("{}", money), "-$1,000.50");
+
+    set_global_locale(Locale::FrFr);
+    assert_eq!(global_locale(), Some(Locale::FrFr));
+    assert_eq!(format!("{}", money), "-1 000,50 $");
+
+    // An explicit locale always wins
+    assert_eq!(money.format_in(Locale::EnUs), "-$1,000.50");
+
+    clear_global_locale();
+    assert_eq!(global_locale(), None);
+    assert_eq!(format!("{}", money), "-$1,000.50");
+}